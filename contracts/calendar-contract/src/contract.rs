@@ -1,51 +1,373 @@
 use crate::error::CalendarError;
 use crate::events;
 use crate::storage;
-use soroban_sdk::{Address, BytesN, Env};
+use crate::types::{AvailabilityWindow, CancellationTier, SlotReservation};
+use access_control::Role;
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol, Vec};
+use vault_types::{BookingRecord, BookingStatus};
+
+/// Maximum number of weekly windows an expert can publish (four per day).
+const MAX_AVAILABILITY_WINDOWS: u32 = 28;
+const SECONDS_PER_DAY: u32 = 86_400;
+/// Maximum number of refund tiers in a cancellation policy.
+const MAX_CANCELLATION_TIERS: u32 = 8;
+/// Refunds are expressed in basis points of the booking deposit.
+const FULL_REFUND_BPS: u32 = 10_000;
+/// 1970-01-01 was a Thursday (day 4 with 0 = Sunday).
+const EPOCH_DAY_OF_WEEK: i64 = 4;
+/// UTC offsets range from UTC-12:00 to UTC+14:00.
+const MIN_TZ_OFFSET_SECONDS: i32 = -12 * 3_600;
+const MAX_TZ_OFFSET_SECONDS: i32 = 14 * 3_600;
 
 pub fn initialize(
     env: &Env,
     admin: &Address,
     vault_address: &Address,
+    registry_address: &Address,
 ) -> Result<(), CalendarError> {
     if storage::has_admin(env) {
         return Err(CalendarError::AlreadyInitialized);
     }
     storage::set_admin(env, admin);
     storage::set_vault_address(env, vault_address);
+    storage::set_registry_address(env, registry_address);
+    access_control::init_admin(env, admin);
     Ok(())
 }
 
-pub fn pause(env: &Env) -> Result<(), CalendarError> {
-    let admin = storage::get_admin(env).ok_or(CalendarError::NotInitialized)?;
-    admin.require_auth();
+pub fn pause(env: &Env, caller: &Address) -> Result<(), CalendarError> {
+    storage::get_admin(env).ok_or(CalendarError::NotInitialized)?;
+    access_control::require_role(env, Role::Pauser, caller)?;
     storage::set_paused(env, true);
     events::contract_paused(env, true);
     Ok(())
 }
 
-pub fn unpause(env: &Env) -> Result<(), CalendarError> {
-    let admin = storage::get_admin(env).ok_or(CalendarError::NotInitialized)?;
-    admin.require_auth();
+pub fn unpause(env: &Env, caller: &Address) -> Result<(), CalendarError> {
+    storage::get_admin(env).ok_or(CalendarError::NotInitialized)?;
+    access_control::require_role(env, Role::Pauser, caller)?;
     storage::set_paused(env, false);
     events::contract_paused(env, false);
     Ok(())
 }
 
-pub fn transfer_admin(env: &Env, new_admin: &Address) -> Result<(), CalendarError> {
+/// Grant `role` to `account` (Admin role only)
+pub fn grant_role(
+    env: &Env,
+    caller: &Address,
+    role: Role,
+    account: &Address,
+) -> Result<(), CalendarError> {
+    access_control::require_role(env, Role::Admin, caller)?;
+    access_control::grant_role(env, role, account, caller);
+    Ok(())
+}
+
+/// Revoke `role` from `account` (Admin role only).
+/// The primary admin keeps the Admin role until admin rights are transferred.
+pub fn revoke_role(
+    env: &Env,
+    caller: &Address,
+    role: Role,
+    account: &Address,
+) -> Result<(), CalendarError> {
+    access_control::require_role(env, Role::Admin, caller)?;
+    let admin = storage::get_admin(env).ok_or(CalendarError::NotInitialized)?;
+    if role == Role::Admin && *account == admin {
+        return Err(CalendarError::Unauthorized);
+    }
+    access_control::revoke_role(env, role, account, caller);
+    Ok(())
+}
+
+/// Hand the stored admin its roles on a contract upgraded from before role storage (Admin-only).
+pub fn migrate_roles(env: &Env) -> Result<(), CalendarError> {
     let admin = storage::get_admin(env).ok_or(CalendarError::NotInitialized)?;
     admin.require_auth();
+    if !access_control::migrate_admin(env, &admin) {
+        return Err(CalendarError::AlreadyInitialized);
+    }
+    Ok(())
+}
+
+/// Nominate `new_admin` as the next admin (Admin-only).
+/// Nothing changes until the nominee calls `accept_admin`; a new proposal replaces any pending one.
+pub fn propose_admin(env: &Env, new_admin: &Address) -> Result<(), CalendarError> {
+    let admin = storage::get_admin(env).ok_or(CalendarError::NotInitialized)?;
     if storage::is_paused(env) {
         return Err(CalendarError::ContractPaused);
     }
-    storage::set_admin(env, new_admin);
-    events::admin_transferred(env, &admin, new_admin);
+    access_control::propose_admin(env, &admin, new_admin);
+    events::admin_proposed(env, &admin, new_admin);
     Ok(())
 }
 
-pub fn upgrade_contract(env: &Env, new_wasm_hash: BytesN<32>) -> Result<(), CalendarError> {
+/// Complete a pending admin transfer (pending admin only).
+pub fn accept_admin(env: &Env) -> Result<(), CalendarError> {
     let admin = storage::get_admin(env).ok_or(CalendarError::NotInitialized)?;
-    admin.require_auth();
-    env.deployer().update_current_contract_wasm(new_wasm_hash);
+    if storage::is_paused(env) {
+        return Err(CalendarError::ContractPaused);
+    }
+    let new_admin = access_control::accept_admin(env, &admin)?;
+    storage::set_admin(env, &new_admin);
+    events::admin_transferred(env, &admin, &new_admin);
+    Ok(())
+}
+
+/// Withdraw a pending admin proposal (Admin-only).
+pub fn cancel_admin_transfer(env: &Env) -> Result<(), CalendarError> {
+    let admin = storage::get_admin(env).ok_or(CalendarError::NotInitialized)?;
+    if storage::is_paused(env) {
+        return Err(CalendarError::ContractPaused);
+    }
+    let pending_admin = access_control::cancel_admin_transfer(env, &admin)?;
+    events::admin_transfer_cancelled(env, &admin, &pending_admin);
     Ok(())
 }
+
+/// Point the calendar at the Identity Registry that verifies experts (Admin role only).
+/// Instances initialized before the registry check existed start without one and
+/// reject every availability update until it is set.
+pub fn set_registry_address(
+    env: &Env,
+    caller: &Address,
+    registry: &Address,
+) -> Result<(), CalendarError> {
+    storage::get_admin(env).ok_or(CalendarError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
+    storage::set_registry_address(env, registry);
+    events::registry_updated(env, registry);
+    Ok(())
+}
+
+/// Replace an expert's recurring weekly availability (Expert-only, must be verified).
+pub fn set_availability(
+    env: &Env,
+    expert: &Address,
+    windows: &Vec<AvailabilityWindow>,
+) -> Result<(), CalendarError> {
+    if storage::is_paused(env) {
+        return Err(CalendarError::ContractPaused);
+    }
+
+    expert.require_auth();
+
+    if !is_expert_verified(env, expert)? {
+        return Err(CalendarError::ExpertNotVerified);
+    }
+
+    if windows.len() > MAX_AVAILABILITY_WINDOWS {
+        return Err(CalendarError::TooManyWindows);
+    }
+
+    for window in windows.iter() {
+        validate_window(&window)?;
+    }
+
+    storage::set_availability(env, expert, windows);
+    events::availability_set(env, expert, windows.len());
+    Ok(())
+}
+
+/// Remove all of an expert's published availability (Expert-only).
+pub fn clear_availability(env: &Env, expert: &Address) -> Result<(), CalendarError> {
+    if storage::is_paused(env) {
+        return Err(CalendarError::ContractPaused);
+    }
+
+    expert.require_auth();
+
+    storage::remove_availability(env, expert);
+    events::availability_cleared(env, expert);
+    Ok(())
+}
+
+/// Reserve `[start_ts, start_ts + duration)` on the expert's calendar for a vault booking (User-only).
+/// The vault booking must exist, be Pending and belong to this user and expert; the slot must
+/// fall inside one of the expert's availability windows and not overlap another live reservation.
+pub fn reserve_slot(
+    env: &Env,
+    user: &Address,
+    expert: &Address,
+    start_ts: u64,
+    duration: u64,
+    booking_id: u64,
+) -> Result<(), CalendarError> {
+    if storage::is_paused(env) {
+        return Err(CalendarError::ContractPaused);
+    }
+
+    user.require_auth();
+
+    // 1. Validate the slot itself
+    let now = env.ledger().timestamp();
+    let end_ts = start_ts
+        .checked_add(duration)
+        .ok_or(CalendarError::InvalidSlot)?;
+    if duration == 0 || start_ts < now {
+        return Err(CalendarError::InvalidSlot);
+    }
+
+    if storage::has_reservation(env, booking_id) {
+        return Err(CalendarError::SlotAlreadyReserved);
+    }
+
+    // 2. Confirm the vault booking backs this reservation
+    let booking = get_vault_booking(env, booking_id)?.ok_or(CalendarError::BookingNotFound)?;
+    if booking.user != *user || booking.expert != *expert || duration > booking.max_duration {
+        return Err(CalendarError::BookingMismatch);
+    }
+    if booking.status != BookingStatus::Pending {
+        return Err(CalendarError::BookingNotPending);
+    }
+
+    // 3. The slot must fit inside the expert's published availability
+    let fits_availability = storage::get_availability(env, expert)
+        .iter()
+        .any(|window| window_contains(&window, start_ts, duration));
+    if !fits_availability {
+        return Err(CalendarError::OutsideAvailability);
+    }
+
+    // 4. Reject overlaps with live reservations, pruning finished or released ones on the way
+    let mut live_slots = Vec::new(env);
+    for reserved_id in storage::get_expert_slots(env, expert).iter() {
+        let Some(reserved) = storage::get_reservation(env, reserved_id) else {
+            continue;
+        };
+        if reserved.end_ts <= now {
+            continue;
+        }
+        if start_ts < reserved.end_ts && reserved.start_ts < end_ts {
+            let still_booked = get_vault_booking(env, reserved_id)?
+                .is_some_and(|b| b.status == BookingStatus::Pending);
+            if still_booked {
+                return Err(CalendarError::SlotOverlap);
+            }
+            continue;
+        }
+        live_slots.push_back(reserved_id);
+    }
+    live_slots.push_back(booking_id);
+
+    let reservation = SlotReservation {
+        booking_id,
+        user: user.clone(),
+        expert: expert.clone(),
+        start_ts,
+        end_ts,
+        created_at: now,
+        cancellation_policy: storage::get_cancellation_policy(env, expert),
+    };
+    storage::set_reservation(env, &reservation);
+    storage::set_expert_slots(env, expert, &live_slots);
+    events::slot_reserved(env, booking_id, expert, start_ts, end_ts);
+
+    Ok(())
+}
+
+/// Replace an expert's cancellation policy (Expert-only).
+/// Tiers must be ordered by strictly decreasing notice with non-increasing refunds.
+/// Existing reservations keep the policy that applied when they were made.
+pub fn set_cancellation_policy(
+    env: &Env,
+    expert: &Address,
+    tiers: &Vec<CancellationTier>,
+) -> Result<(), CalendarError> {
+    if storage::is_paused(env) {
+        return Err(CalendarError::ContractPaused);
+    }
+
+    expert.require_auth();
+
+    if tiers.len() > MAX_CANCELLATION_TIERS {
+        return Err(CalendarError::InvalidCancellationPolicy);
+    }
+
+    let mut previous: Option<CancellationTier> = None;
+    for tier in tiers.iter() {
+        if tier.refund_bps > FULL_REFUND_BPS {
+            return Err(CalendarError::InvalidCancellationPolicy);
+        }
+        if let Some(prev) = previous {
+            if tier.min_notice_seconds >= prev.min_notice_seconds
+                || tier.refund_bps > prev.refund_bps
+            {
+                return Err(CalendarError::InvalidCancellationPolicy);
+            }
+        }
+        previous = Some(tier);
+    }
+
+    storage::set_cancellation_policy(env, expert, tiers);
+    events::cancellation_policy_set(env, expert, tiers.len());
+    Ok(())
+}
+
+/// Share of the deposit (in bps) refunded if the booking is cancelled now.
+/// Bookings without a reservation, or reserved under an empty policy, are fully refundable.
+/// Notice shorter than every tier forfeits the whole deposit.
+pub fn get_cancellation_refund_bps(env: &Env, booking_id: u64) -> u32 {
+    let Some(reservation) = storage::get_reservation(env, booking_id) else {
+        return FULL_REFUND_BPS;
+    };
+    if reservation.cancellation_policy.is_empty() {
+        return FULL_REFUND_BPS;
+    }
+
+    let notice = reservation
+        .start_ts
+        .saturating_sub(env.ledger().timestamp());
+    reservation
+        .cancellation_policy
+        .iter()
+        .find(|tier| notice >= tier.min_notice_seconds)
+        .map(|tier| tier.refund_bps)
+        .unwrap_or(0)
+}
+
+/// True if `[start_ts, start_ts + duration)` lies within `window` on the expert's local clock.
+fn window_contains(window: &AvailabilityWindow, start_ts: u64, duration: u64) -> bool {
+    let local = start_ts as i64 + window.tz_offset_seconds as i64;
+    if local < 0 {
+        return false;
+    }
+    let day_of_week = (local / SECONDS_PER_DAY as i64 + EPOCH_DAY_OF_WEEK) % 7;
+    let second_of_day = (local % SECONDS_PER_DAY as i64) as u64;
+
+    day_of_week == window.day_of_week as i64
+        && second_of_day >= window.start_second as u64
+        && second_of_day + duration <= window.end_second as u64
+}
+
+/// Fetch a booking from the PaymentVault via cross-contract call
+fn get_vault_booking(env: &Env, booking_id: u64) -> Result<Option<BookingRecord>, CalendarError> {
+    let vault = storage::get_vault_address(env).ok_or(CalendarError::NotInitialized)?;
+    Ok(env.invoke_contract(
+        &vault,
+        &Symbol::new(env, "get_booking"),
+        vec![env, booking_id.into_val(env)],
+    ))
+}
+
+fn validate_window(window: &AvailabilityWindow) -> Result<(), CalendarError> {
+    if window.day_of_week > 6
+        || window.start_second >= window.end_second
+        || window.end_second > SECONDS_PER_DAY
+        || window.tz_offset_seconds < MIN_TZ_OFFSET_SECONDS
+        || window.tz_offset_seconds > MAX_TZ_OFFSET_SECONDS
+    {
+        return Err(CalendarError::InvalidAvailability);
+    }
+    Ok(())
+}
+
+/// Check the expert's verification status via Identity Registry cross-contract call
+fn is_expert_verified(env: &Env, expert: &Address) -> Result<bool, CalendarError> {
+    let registry = storage::get_registry_address(env).ok_or(CalendarError::NotInitialized)?;
+    Ok(env.invoke_contract(
+        &registry,
+        &Symbol::new(env, "is_verified"),
+        vec![env, expert.into_val(env)],
+    ))
+}
@@ -1,4 +1,6 @@
+use access_control::AccessError;
 use soroban_sdk::contracterror;
+use upgrade_timelock::UpgradeError;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -7,4 +9,39 @@ pub enum CalendarError {
     NotInitialized = 1,
     AlreadyInitialized = 2,
     ContractPaused = 3,
+    ExpertNotVerified = 4,
+    InvalidAvailability = 5,
+    TooManyWindows = 6,
+    InvalidSlot = 7,
+    SlotOverlap = 8,
+    OutsideAvailability = 9,
+    BookingNotFound = 10,
+    BookingMismatch = 11,
+    BookingNotPending = 12,
+    SlotAlreadyReserved = 13,
+    InvalidCancellationPolicy = 14,
+    Unauthorized = 15,
+    NoPendingAdmin = 16,
+    InvalidUpgradeDelay = 17,
+    NoPendingUpgrade = 18,
+    UpgradeNotReady = 19,
+}
+
+impl From<AccessError> for CalendarError {
+    fn from(err: AccessError) -> Self {
+        match err {
+            AccessError::MissingRole => CalendarError::Unauthorized,
+            AccessError::NoPendingAdmin => CalendarError::NoPendingAdmin,
+        }
+    }
+}
+
+impl From<UpgradeError> for CalendarError {
+    fn from(err: UpgradeError) -> Self {
+        match err {
+            UpgradeError::InvalidDelay => CalendarError::InvalidUpgradeDelay,
+            UpgradeError::NoPendingUpgrade => CalendarError::NoPendingUpgrade,
+            UpgradeError::UpgradeNotReady => CalendarError::UpgradeNotReady,
+        }
+    }
 }
@@ -11,3 +11,41 @@ pub fn admin_transferred(env: &Env, old_admin: &Address, new_admin: &Address) {
     env.events()
         .publish(topics, (old_admin.clone(), new_admin.clone()));
 }
+
+pub fn admin_proposed(env: &Env, admin: &Address, pending_admin: &Address) {
+    let topics = (symbol_short!("adm_prop"),);
+    env.events()
+        .publish(topics, (admin.clone(), pending_admin.clone()));
+}
+
+pub fn admin_transfer_cancelled(env: &Env, admin: &Address, pending_admin: &Address) {
+    let topics = (symbol_short!("adm_cncl"),);
+    env.events()
+        .publish(topics, (admin.clone(), pending_admin.clone()));
+}
+
+pub fn registry_updated(env: &Env, registry: &Address) {
+    let topics = (symbol_short!("reg_upd"),);
+    env.events().publish(topics, registry.clone());
+}
+
+pub fn availability_set(env: &Env, expert: &Address, window_count: u32) {
+    let topics = (symbol_short!("avail_set"), expert.clone());
+    env.events().publish(topics, window_count);
+}
+
+pub fn availability_cleared(env: &Env, expert: &Address) {
+    let topics = (symbol_short!("avail_clr"), expert.clone());
+    env.events().publish(topics, ());
+}
+
+pub fn slot_reserved(env: &Env, booking_id: u64, expert: &Address, start_ts: u64, end_ts: u64) {
+    let topics = (symbol_short!("slot_rsv"), booking_id);
+    env.events()
+        .publish(topics, (expert.clone(), start_ts, end_ts));
+}
+
+pub fn cancellation_policy_set(env: &Env, expert: &Address, tier_count: u32) {
+    let topics = (symbol_short!("cncl_pol"), expert.clone());
+    env.events().publish(topics, tier_count);
+}
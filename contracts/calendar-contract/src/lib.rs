@@ -9,30 +9,195 @@ mod test;
 mod types;
 
 use crate::error::CalendarError;
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use crate::types::{AvailabilityWindow, CancellationTier, SlotReservation};
+use access_control::Role;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+use upgrade_timelock::PendingUpgrade;
 
 #[contract]
 pub struct CalendarContract;
 
 #[contractimpl]
 impl CalendarContract {
-    pub fn init(env: Env, admin: Address, vault_address: Address) -> Result<(), CalendarError> {
-        contract::initialize(&env, &admin, &vault_address)
+    pub fn init(
+        env: Env,
+        admin: Address,
+        vault_address: Address,
+        registry_address: Address,
+    ) -> Result<(), CalendarError> {
+        contract::initialize(&env, &admin, &vault_address, &registry_address)
     }
 
-    pub fn pause(env: Env) -> Result<(), CalendarError> {
-        contract::pause(&env)
+    /// Pause reservations and availability changes (Pauser role only)
+    pub fn pause(env: Env, caller: Address) -> Result<(), CalendarError> {
+        contract::pause(&env, &caller)
     }
 
-    pub fn unpause(env: Env) -> Result<(), CalendarError> {
-        contract::unpause(&env)
+    /// Resume normal operation (Pauser role only)
+    pub fn unpause(env: Env, caller: Address) -> Result<(), CalendarError> {
+        contract::unpause(&env, &caller)
     }
 
-    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), CalendarError> {
-        contract::transfer_admin(&env, &new_admin)
+    /// Grant a role to an account (Admin role only)
+    pub fn grant_role(
+        env: Env,
+        caller: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), CalendarError> {
+        contract::grant_role(&env, &caller, role, &account)
     }
 
-    pub fn upgrade_contract(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), CalendarError> {
-        contract::upgrade_contract(&env, new_wasm_hash)
+    /// Revoke a role from an account (Admin role only)
+    pub fn revoke_role(
+        env: Env,
+        caller: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), CalendarError> {
+        contract::revoke_role(&env, &caller, role, &account)
+    }
+
+    /// Grant the admin roles to the stored admin after upgrading a contract deployed
+    /// before role storage existed (Admin-only)
+    pub fn migrate_roles(env: Env) -> Result<(), CalendarError> {
+        contract::migrate_roles(&env)
+    }
+
+    /// Check whether an account holds a role
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        access_control::has_role(&env, role, &account)
+    }
+
+    /// Propose a new admin (Admin-only)
+    /// The current admin keeps full control until the nominee calls `accept_admin`
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), CalendarError> {
+        contract::propose_admin(&env, &new_admin)
+    }
+
+    /// Accept a pending admin proposal (pending admin only)
+    /// The old admin loses its privileges at this point
+    pub fn accept_admin(env: Env) -> Result<(), CalendarError> {
+        contract::accept_admin(&env)
+    }
+
+    /// Cancel a pending admin proposal (Admin-only)
+    pub fn cancel_admin_transfer(env: Env) -> Result<(), CalendarError> {
+        contract::cancel_admin_transfer(&env)
+    }
+
+    /// Get the proposed admin awaiting acceptance, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        access_control::get_pending_admin(&env)
+    }
+
+    /// Announce a WASM upgrade (Upgrader role only)
+    /// It becomes executable after the upgrade delay, giving users time to exit
+    pub fn schedule_upgrade(
+        env: Env,
+        caller: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), CalendarError> {
+        upgrade_timelock::schedule_as_upgrader(&env, &caller, new_wasm_hash)
+    }
+
+    /// Apply the scheduled upgrade once its delay has elapsed (Upgrader role only)
+    pub fn execute_upgrade(env: Env, caller: Address) -> Result<(), CalendarError> {
+        upgrade_timelock::execute_as_upgrader(&env, &caller)
+    }
+
+    /// Cancel the scheduled upgrade (Upgrader role only)
+    pub fn cancel_upgrade(env: Env, caller: Address) -> Result<(), CalendarError> {
+        upgrade_timelock::cancel_as_upgrader(&env, &caller)
+    }
+
+    /// Set the delay in seconds between scheduling and executing an upgrade (Admin role only)
+    pub fn set_upgrade_delay(env: Env, caller: Address, delay: u64) -> Result<(), CalendarError> {
+        upgrade_timelock::set_delay_as_admin(&env, &caller, delay)
+    }
+
+    /// Get the scheduled upgrade, if any
+    pub fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade> {
+        upgrade_timelock::get_pending(&env)
+    }
+
+    /// Get the delay in seconds between scheduling and executing an upgrade
+    pub fn get_upgrade_delay(env: Env) -> u64 {
+        upgrade_timelock::get_delay(&env)
+    }
+
+    /// Set the Identity Registry used to verify experts (Admin role only)
+    pub fn set_registry_address(
+        env: Env,
+        caller: Address,
+        registry: Address,
+    ) -> Result<(), CalendarError> {
+        contract::set_registry_address(&env, &caller, &registry)
+    }
+
+    /// Get the configured Identity Registry, if any
+    pub fn get_registry_address(env: Env) -> Option<Address> {
+        storage::get_registry_address(&env)
+    }
+
+    /// Publish the expert's recurring weekly availability, replacing any previous windows.
+    /// Each window is a day of week, a start/end second-of-day and a UTC offset.
+    /// The expert must be verified in the Identity Registry.
+    pub fn set_availability(
+        env: Env,
+        expert: Address,
+        windows: Vec<AvailabilityWindow>,
+    ) -> Result<(), CalendarError> {
+        contract::set_availability(&env, &expert, &windows)
+    }
+
+    /// Remove all of the expert's published availability.
+    pub fn clear_availability(env: Env, expert: Address) -> Result<(), CalendarError> {
+        contract::clear_availability(&env, &expert)
+    }
+
+    /// Get the expert's published weekly availability.
+    pub fn get_availability(env: Env, expert: Address) -> Vec<AvailabilityWindow> {
+        storage::get_availability(&env, &expert)
+    }
+
+    /// Reserve a time slot for an existing vault booking (User-only).
+    /// The booking must be Pending and belong to `user` and `expert`. The slot must fall
+    /// within the expert's availability and must not overlap any of their live reservations.
+    pub fn reserve_slot(
+        env: Env,
+        user: Address,
+        expert: Address,
+        start_ts: u64,
+        duration: u64,
+        booking_id: u64,
+    ) -> Result<(), CalendarError> {
+        contract::reserve_slot(&env, &user, &expert, start_ts, duration, booking_id)
+    }
+
+    /// Get the slot reserved for a vault booking, if any.
+    pub fn get_reservation(env: Env, booking_id: u64) -> Option<SlotReservation> {
+        storage::get_reservation(&env, booking_id)
+    }
+
+    /// Replace the expert's cancellation policy (Expert-only).
+    /// Tiers are ordered longest notice first, e.g. 24h -> 100%, 1h -> 50%, 0 -> 0%.
+    pub fn set_cancellation_policy(
+        env: Env,
+        expert: Address,
+        tiers: Vec<CancellationTier>,
+    ) -> Result<(), CalendarError> {
+        contract::set_cancellation_policy(&env, &expert, &tiers)
+    }
+
+    /// Get the expert's current cancellation policy.
+    pub fn get_cancellation_policy(env: Env, expert: Address) -> Vec<CancellationTier> {
+        storage::get_cancellation_policy(&env, &expert)
+    }
+
+    /// Refund share in basis points if the booking were cancelled now.
+    /// Used by the PaymentVault when a user cancels a booking.
+    pub fn get_cancellation_refund_bps(env: Env, booking_id: u64) -> u32 {
+        contract::get_cancellation_refund_bps(&env, booking_id)
     }
 }
@@ -1,11 +1,41 @@
-use soroban_sdk::{contracttype, Address, Env};
+use crate::types::{AvailabilityWindow, CancellationTier, SlotReservation};
+use soroban_sdk::{contracttype, Address, Env, IntoVal, TryFromVal, Val, Vec};
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Admin,
     VaultAddress,
+    RegistryAddress,
     IsPaused,
+    Availability(Address),       // Expert -> Vec<AvailabilityWindow>
+    Reservation(u64),            // Vault booking ID -> SlotReservation
+    ExpertSlots(Address),        // Expert -> Vec<u64> of booking IDs with upcoming reservations
+    CancellationPolicy(Address), // Expert -> Vec<CancellationTier>, longest notice first
+}
+
+// --- TTL (Time To Live) ---
+// Availability, policies and reservations must outlive the slots they describe:
+// every write or read pushes the entry out to a year once less than ~2 months remain.
+
+const LEDGERS_THRESHOLD: u32 = 1_000_000; // 2 months
+const LEDGERS_EXTEND_TO: u32 = 6_300_000; // 1 year
+
+fn set_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+fn get_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+    }
+    value
 }
 
 // --- Admin ---
@@ -32,6 +62,18 @@ pub fn get_vault_address(env: &Env) -> Option<Address> {
     env.storage().instance().get(&DataKey::VaultAddress)
 }
 
+// --- Registry ---
+
+pub fn set_registry_address(env: &Env, registry: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RegistryAddress, registry);
+}
+
+pub fn get_registry_address(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::RegistryAddress)
+}
+
 // --- Pause ---
 
 pub fn is_paused(env: &Env) -> bool {
@@ -44,3 +86,57 @@ pub fn is_paused(env: &Env) -> bool {
 pub fn set_paused(env: &Env, paused: bool) {
     env.storage().instance().set(&DataKey::IsPaused, &paused);
 }
+
+// --- Availability ---
+
+pub fn set_availability(env: &Env, expert: &Address, windows: &Vec<AvailabilityWindow>) {
+    set_persistent(env, &DataKey::Availability(expert.clone()), windows);
+}
+
+pub fn get_availability(env: &Env, expert: &Address) -> Vec<AvailabilityWindow> {
+    get_persistent(env, &DataKey::Availability(expert.clone())).unwrap_or(Vec::new(env))
+}
+
+pub fn remove_availability(env: &Env, expert: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Availability(expert.clone()));
+}
+
+// --- Cancellation Policy ---
+
+pub fn set_cancellation_policy(env: &Env, expert: &Address, tiers: &Vec<CancellationTier>) {
+    set_persistent(env, &DataKey::CancellationPolicy(expert.clone()), tiers);
+}
+
+pub fn get_cancellation_policy(env: &Env, expert: &Address) -> Vec<CancellationTier> {
+    get_persistent(env, &DataKey::CancellationPolicy(expert.clone())).unwrap_or(Vec::new(env))
+}
+
+// --- Reservations ---
+
+pub fn set_reservation(env: &Env, reservation: &SlotReservation) {
+    set_persistent(
+        env,
+        &DataKey::Reservation(reservation.booking_id),
+        reservation,
+    );
+}
+
+pub fn get_reservation(env: &Env, booking_id: u64) -> Option<SlotReservation> {
+    get_persistent(env, &DataKey::Reservation(booking_id))
+}
+
+pub fn has_reservation(env: &Env, booking_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Reservation(booking_id))
+}
+
+pub fn get_expert_slots(env: &Env, expert: &Address) -> Vec<u64> {
+    get_persistent(env, &DataKey::ExpertSlots(expert.clone())).unwrap_or(Vec::new(env))
+}
+
+pub fn set_expert_slots(env: &Env, expert: &Address, booking_ids: &Vec<u64>) {
+    set_persistent(env, &DataKey::ExpertSlots(expert.clone()), booking_ids);
+}
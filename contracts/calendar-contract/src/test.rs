@@ -2,38 +2,92 @@
 
 use super::*;
 use crate::error::CalendarError;
-use soroban_sdk::{testutils::Address as _, testutils::Events, Address, Env, Symbol, TryIntoVal};
+use crate::types::{AvailabilityWindow, CancellationTier};
+use access_control::Role;
+use soroban_sdk::{
+    testutils::storage::Persistent as _, testutils::Address as _, testutils::Events,
+    testutils::Ledger, vec, Address, BytesN, Env, Symbol, TryIntoVal, Vec,
+};
+use vault_types::{BookingRecord, BookingStatus};
 
-fn setup() -> (Env, Address, Address, CalendarContractClient<'static>) {
+// Mock Identity Registry returning a configurable value for is_verified
+mod mock_registry {
+    use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+    #[contract]
+    pub struct MockRegistry;
+
+    #[contractimpl]
+    impl MockRegistry {
+        pub fn is_verified(env: Env, _expert: Address) -> bool {
+            let key = Symbol::new(&env, "is_verified");
+            env.storage().instance().get(&key).unwrap_or(true)
+        }
+
+        pub fn set_verified(env: Env, verified: bool) {
+            let key = Symbol::new(&env, "is_verified");
+            env.storage().instance().set(&key, &verified);
+        }
+    }
+}
+
+// Mock PaymentVault serving bookings stored by the test
+mod mock_vault {
+    use soroban_sdk::{contract, contractimpl, Env};
+    use vault_types::BookingRecord;
+
+    #[contract]
+    pub struct MockVault;
+
+    #[contractimpl]
+    impl MockVault {
+        pub fn get_booking(env: Env, booking_id: u64) -> Option<BookingRecord> {
+            env.storage().instance().get(&booking_id)
+        }
+
+        pub fn set_booking(env: Env, booking: BookingRecord) {
+            env.storage().instance().set(&booking.id, &booking);
+        }
+    }
+}
+
+fn setup() -> (
+    Env,
+    Address,
+    Address,
+    Address,
+    CalendarContractClient<'static>,
+) {
     let env = Env::default();
     env.mock_all_auths();
     let contract_id = env.register(CalendarContract, ());
     let client = CalendarContractClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
-    let vault = Address::generate(&env);
-    (env, admin, vault, client)
+    let vault = env.register(mock_vault::MockVault, ());
+    let registry = env.register(mock_registry::MockRegistry, ());
+    (env, admin, vault, registry, client)
 }
 
 #[test]
 fn test_initialize() {
-    let (_env, admin, vault, client) = setup();
-    let res = client.try_init(&admin, &vault);
+    let (_env, admin, vault, registry, client) = setup();
+    let res = client.try_init(&admin, &vault, &registry);
     assert!(res.is_ok());
 }
 
 #[test]
 fn test_initialize_twice_fails() {
-    let (_env, admin, vault, client) = setup();
-    client.init(&admin, &vault);
-    let res = client.try_init(&admin, &vault);
+    let (_env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    let res = client.try_init(&admin, &vault, &registry);
     assert_eq!(res, Err(Ok(CalendarError::AlreadyInitialized)));
 }
 
 #[test]
 fn test_pause() {
-    let (env, admin, vault, client) = setup();
-    client.init(&admin, &vault);
-    client.pause();
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    client.pause(&admin);
 
     let events = env.events().all();
     let last = events.last().unwrap();
@@ -43,10 +97,10 @@ fn test_pause() {
 
 #[test]
 fn test_unpause() {
-    let (env, admin, vault, client) = setup();
-    client.init(&admin, &vault);
-    client.pause();
-    client.unpause();
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    client.pause(&admin);
+    client.unpause(&admin);
 
     let events = env.events().all();
     let last = events.last().unwrap();
@@ -56,51 +110,91 @@ fn test_unpause() {
 
 #[test]
 fn test_pause_not_initialized() {
-    let (_env, _admin, _vault, client) = setup();
-    let res = client.try_pause();
+    let (_env, admin, _vault, _registry, client) = setup();
+    let res = client.try_pause(&admin);
     assert_eq!(res, Err(Ok(CalendarError::NotInitialized)));
 }
 
 #[test]
 fn test_transfer_admin() {
-    let (env, admin, vault, client) = setup();
-    client.init(&admin, &vault);
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
     let new_admin = Address::generate(&env);
-    let res = client.try_transfer_admin(&new_admin);
-    assert!(res.is_ok());
+    client.propose_admin(&new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    // The current admin stays in charge until the nominee accepts
+    assert!(client.has_role(&Role::Admin, &admin));
+    client.accept_admin();
+    assert_eq!(client.get_pending_admin(), None);
+    assert!(client.has_role(&Role::Admin, &new_admin));
 }
 
 #[test]
 fn test_transfer_admin_emits_event() {
-    let (env, admin, vault, client) = setup();
-    client.init(&admin, &vault);
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
     let new_admin = Address::generate(&env);
-    client.transfer_admin(&new_admin);
+    client.propose_admin(&new_admin);
 
+    let events = env.events().all();
+    let last = events.last().unwrap();
+    let topic: Symbol = last.1.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(topic, Symbol::new(&env, "adm_prop"));
+
+    client.accept_admin();
     let events = env.events().all();
     let last = events.last().unwrap();
     let topic: Symbol = last.1.get(0).unwrap().try_into_val(&env).unwrap();
     assert_eq!(topic, Symbol::new(&env, "adm_xfer"));
 }
 
+#[test]
+fn test_cancel_admin_transfer() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    let new_admin = Address::generate(&env);
+    client.propose_admin(&new_admin);
+    client.cancel_admin_transfer();
+
+    let events = env.events().all();
+    let last = events.last().unwrap();
+    let topic: Symbol = last.1.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(topic, Symbol::new(&env, "adm_cncl"));
+
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(CalendarError::NoPendingAdmin))
+    );
+    assert_eq!(
+        client.try_cancel_admin_transfer(),
+        Err(Ok(CalendarError::NoPendingAdmin))
+    );
+}
+
 #[test]
 fn test_pause_blocks_transfer_admin() {
-    let (env, admin, vault, client) = setup();
-    client.init(&admin, &vault);
-    client.pause();
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
     let new_admin = Address::generate(&env);
-    let res = client.try_transfer_admin(&new_admin);
-    assert_eq!(res, Err(Ok(CalendarError::ContractPaused)));
+    client.propose_admin(&new_admin);
+    client.pause(&admin);
+    let paused = Err(Ok(CalendarError::ContractPaused));
+    assert_eq!(client.try_propose_admin(&new_admin), paused);
+    assert_eq!(client.try_accept_admin(), paused);
+    assert_eq!(client.try_cancel_admin_transfer(), paused);
+    assert_eq!(client.get_pending_admin(), Some(new_admin));
 }
 
 #[test]
 fn test_unpause_restores_transfer_admin() {
-    let (env, admin, vault, client) = setup();
-    client.init(&admin, &vault);
-    client.pause();
-    client.unpause();
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    client.pause(&admin);
+    client.unpause(&admin);
     let new_admin = Address::generate(&env);
-    let res = client.try_transfer_admin(&new_admin);
+    let res = client.try_propose_admin(&new_admin);
     assert!(res.is_ok());
 }
 
@@ -112,14 +206,15 @@ fn test_pause_requires_auth() {
     let client = CalendarContractClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     let vault = Address::generate(&env);
+    let registry = Address::generate(&env);
 
     // Init with mocked auth
     env.mock_all_auths();
-    client.init(&admin, &vault);
+    client.init(&admin, &vault, &registry);
 
     // Clear auth — pause should panic
     env.mock_auths(&[]);
-    client.pause();
+    client.pause(&admin);
 }
 
 #[test]
@@ -130,11 +225,736 @@ fn test_transfer_admin_requires_auth() {
     let client = CalendarContractClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     let vault = Address::generate(&env);
+    let registry = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init(&admin, &vault, &registry);
+
+    env.mock_auths(&[]);
+    client.propose_admin(&new_admin);
+}
+
+#[test]
+#[should_panic]
+fn test_accept_admin_requires_pending_admin_auth() {
+    let env = Env::default();
+    let contract_id = env.register(CalendarContract, ());
+    let client = CalendarContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let vault = Address::generate(&env);
+    let registry = Address::generate(&env);
     let new_admin = Address::generate(&env);
 
     env.mock_all_auths();
-    client.init(&admin, &vault);
+    client.init(&admin, &vault, &registry);
+    client.propose_admin(&new_admin);
 
     env.mock_auths(&[]);
-    client.transfer_admin(&new_admin);
+    client.accept_admin();
+}
+
+// ==================== Role Tests ====================
+
+#[test]
+fn test_init_grants_admin_roles() {
+    let (_env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    assert!(client.has_role(&Role::Admin, &admin));
+    assert!(client.has_role(&Role::Pauser, &admin));
+    assert!(client.has_role(&Role::Upgrader, &admin));
+    assert!(!client.has_role(&Role::Moderator, &admin));
+}
+
+#[test]
+fn test_pauser_role_can_pause() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    let guardian = Address::generate(&env);
+
+    assert_eq!(
+        client.try_pause(&guardian),
+        Err(Ok(CalendarError::Unauthorized))
+    );
+
+    client.grant_role(&admin, &Role::Pauser, &guardian);
+    client.pause(&guardian);
+    client.unpause(&guardian);
+
+    client.revoke_role(&admin, &Role::Pauser, &guardian);
+    assert!(!client.has_role(&Role::Pauser, &guardian));
+    assert_eq!(
+        client.try_pause(&guardian),
+        Err(Ok(CalendarError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_pauser_cannot_upgrade() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    let guardian = Address::generate(&env);
+    client.grant_role(&admin, &Role::Pauser, &guardian);
+
+    let hash = BytesN::from_array(&env, &[0u8; 32]);
+    let res = client.try_schedule_upgrade(&guardian, &hash);
+    assert_eq!(res, Err(Ok(CalendarError::Unauthorized)));
+}
+
+#[test]
+fn test_grant_role_emits_event() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    let guardian = Address::generate(&env);
+    client.grant_role(&admin, &Role::Pauser, &guardian);
+
+    let events = env.events().all();
+    let last = events.last().unwrap();
+    let topic: Symbol = last.1.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(topic, Symbol::new(&env, "role_grnt"));
+}
+
+#[test]
+fn test_non_admin_cannot_grant_role() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    let stranger = Address::generate(&env);
+
+    let res = client.try_grant_role(&stranger, &Role::Pauser, &stranger);
+    assert_eq!(res, Err(Ok(CalendarError::Unauthorized)));
+}
+
+#[test]
+fn test_cannot_revoke_primary_admin_role() {
+    let (_env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+
+    let res = client.try_revoke_role(&admin, &Role::Admin, &admin);
+    assert_eq!(res, Err(Ok(CalendarError::Unauthorized)));
+}
+
+#[test]
+fn test_transfer_admin_moves_roles() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    let new_admin = Address::generate(&env);
+    client.propose_admin(&new_admin);
+    client.accept_admin();
+
+    assert!(!client.has_role(&Role::Admin, &admin));
+    assert!(!client.has_role(&Role::Pauser, &admin));
+    assert!(client.has_role(&Role::Admin, &new_admin));
+    assert!(client.has_role(&Role::Upgrader, &new_admin));
+    assert_eq!(
+        client.try_pause(&admin),
+        Err(Ok(CalendarError::Unauthorized))
+    );
+}
+
+// ==================== Upgrade Tests ====================
+
+#[test]
+fn test_schedule_upgrade_is_timelocked() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(1_000);
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+    client.schedule_upgrade(&admin, &hash);
+
+    let pending = client.get_pending_upgrade().unwrap();
+    assert_eq!(pending.wasm_hash, hash);
+    assert_eq!(pending.executable_at, 1_000 + client.get_upgrade_delay());
+
+    env.ledger().set_timestamp(pending.executable_at - 1);
+    assert_eq!(
+        client.try_execute_upgrade(&admin),
+        Err(Ok(CalendarError::UpgradeNotReady))
+    );
+
+    client.cancel_upgrade(&admin);
+    assert_eq!(client.get_pending_upgrade(), None);
+    assert_eq!(
+        client.try_execute_upgrade(&admin),
+        Err(Ok(CalendarError::NoPendingUpgrade))
+    );
+}
+
+#[test]
+fn test_set_upgrade_delay() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    let upgrader = Address::generate(&env);
+    client.grant_role(&admin, &Role::Upgrader, &upgrader);
+
+    client.set_upgrade_delay(&admin, &(7 * 86_400));
+    assert_eq!(client.get_upgrade_delay(), 7 * 86_400);
+    assert_eq!(
+        client.try_set_upgrade_delay(&admin, &60),
+        Err(Ok(CalendarError::InvalidUpgradeDelay))
+    );
+
+    // Upgraders cannot shorten their own notice period
+    assert_eq!(
+        client.try_set_upgrade_delay(&upgrader, &86_400),
+        Err(Ok(CalendarError::Unauthorized))
+    );
+}
+
+// ==================== Availability Tests ====================
+
+fn window(day_of_week: u32, start_second: u32, end_second: u32) -> AvailabilityWindow {
+    AvailabilityWindow {
+        day_of_week,
+        start_second,
+        end_second,
+        tz_offset_seconds: 0,
+    }
+}
+
+#[test]
+fn test_set_and_get_availability() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    let expert = Address::generate(&env);
+
+    // Mondays and Wednesdays, 09:00-17:00 UTC-5
+    let windows = vec![
+        &env,
+        AvailabilityWindow {
+            day_of_week: 1,
+            start_second: 9 * 3_600,
+            end_second: 17 * 3_600,
+            tz_offset_seconds: -5 * 3_600,
+        },
+        AvailabilityWindow {
+            day_of_week: 3,
+            start_second: 9 * 3_600,
+            end_second: 17 * 3_600,
+            tz_offset_seconds: -5 * 3_600,
+        },
+    ];
+    client.set_availability(&expert, &windows);
+
+    let events = env.events().all();
+    let last = events.last().unwrap();
+    let topic: Symbol = last.1.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(topic, Symbol::new(&env, "avail_set"));
+
+    assert_eq!(client.get_availability(&expert), windows);
+}
+
+#[test]
+fn test_set_availability_replaces_previous_windows() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    let expert = Address::generate(&env);
+
+    client.set_availability(
+        &expert,
+        &vec![&env, window(1, 0, 3_600), window(2, 0, 3_600)],
+    );
+    client.set_availability(&expert, &vec![&env, window(5, 3_600, 7_200)]);
+
+    assert_eq!(
+        client.get_availability(&expert),
+        vec![&env, window(5, 3_600, 7_200)]
+    );
+}
+
+#[test]
+fn test_clear_availability() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    let expert = Address::generate(&env);
+
+    client.set_availability(&expert, &vec![&env, window(1, 0, 3_600)]);
+    client.clear_availability(&expert);
+
+    assert_eq!(client.get_availability(&expert).len(), 0);
+}
+
+#[test]
+fn test_set_availability_rejects_unverified_expert() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    mock_registry::MockRegistryClient::new(&env, &registry).set_verified(&false);
+    let expert = Address::generate(&env);
+
+    let res = client.try_set_availability(&expert, &vec![&env, window(1, 0, 3_600)]);
+    assert_eq!(res, Err(Ok(CalendarError::ExpertNotVerified)));
+}
+
+#[test]
+fn test_set_registry_address_repairs_pre_registry_instance() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    // Instances deployed before the registry check have no registry stored
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .remove(&crate::storage::DataKey::RegistryAddress)
+    });
+    let expert = Address::generate(&env);
+    let windows = vec![&env, window(1, 0, 3_600)];
+    let res = client.try_set_availability(&expert, &windows);
+    assert_eq!(res, Err(Ok(CalendarError::NotInitialized)));
+
+    let stranger = Address::generate(&env);
+    let res = client.try_set_registry_address(&stranger, &registry);
+    assert_eq!(res, Err(Ok(CalendarError::Unauthorized)));
+
+    client.set_registry_address(&admin, &registry);
+    assert_eq!(client.get_registry_address(), Some(registry));
+    client.set_availability(&expert, &windows);
+    assert_eq!(client.get_availability(&expert).len(), 1);
+}
+
+#[test]
+fn test_set_availability_rejects_invalid_windows() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    let expert = Address::generate(&env);
+
+    let invalid = [
+        window(7, 0, 3_600),     // no eighth day
+        window(1, 3_600, 3_600), // empty window
+        window(1, 7_200, 3_600), // ends before it starts
+        window(1, 0, 86_401),    // past the end of the day
+        AvailabilityWindow {
+            tz_offset_seconds: 15 * 3_600,
+            ..window(1, 0, 3_600)
+        },
+    ];
+    for w in invalid {
+        let res = client.try_set_availability(&expert, &vec![&env, w]);
+        assert_eq!(res, Err(Ok(CalendarError::InvalidAvailability)));
+    }
+}
+
+#[test]
+fn test_set_availability_rejects_too_many_windows() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    let expert = Address::generate(&env);
+
+    let mut windows = Vec::new(&env);
+    for i in 0..29u32 {
+        windows.push_back(window(i % 7, i * 60, i * 60 + 30));
+    }
+    let res = client.try_set_availability(&expert, &windows);
+    assert_eq!(res, Err(Ok(CalendarError::TooManyWindows)));
+}
+
+#[test]
+fn test_pause_blocks_set_availability() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    client.pause(&admin);
+    let expert = Address::generate(&env);
+
+    let res = client.try_set_availability(&expert, &vec![&env, window(1, 0, 3_600)]);
+    assert_eq!(res, Err(Ok(CalendarError::ContractPaused)));
+}
+
+// ==================== Slot Reservation Tests ====================
+
+/// 2024-01-01 00:00:00 UTC, a Monday
+const MONDAY: u64 = 1_704_067_200;
+const HOUR: u64 = 3_600;
+
+/// Record a booking on the mock vault
+fn vault_booking(
+    env: &Env,
+    vault: &Address,
+    id: u64,
+    user: &Address,
+    expert: &Address,
+    status: BookingStatus,
+) {
+    mock_vault::MockVaultClient::new(env, vault).set_booking(&BookingRecord {
+        id,
+        user: user.clone(),
+        expert: expert.clone(),
+        token: Address::generate(env),
+        rate_per_second: 10,
+        max_duration: HOUR,
+        total_deposit: 36_000,
+        status,
+        created_at: MONDAY,
+        started_at: None,
+        dispute_user_refund: None,
+        dispute_expert_pay: None,
+        dispute_remainder_recovered: false,
+        withdrawn_amount: 0,
+    });
+}
+
+#[test]
+fn test_reserve_slot() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let user = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Pending);
+
+    let start = MONDAY + 10 * HOUR;
+    client.reserve_slot(&user, &expert, &start, &HOUR, &1);
+
+    let events = env.events().all();
+    let topic: Symbol = events
+        .last()
+        .unwrap()
+        .1
+        .get(0)
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(topic, Symbol::new(&env, "slot_rsv"));
+
+    let reservation = client.get_reservation(&1).unwrap();
+    assert_eq!(reservation.user, user);
+    assert_eq!(reservation.expert, expert);
+    assert_eq!(reservation.start_ts, start);
+    assert_eq!(reservation.end_ts, start + HOUR);
+}
+
+#[test]
+fn test_reserve_slot_rejects_overlap() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let user_a = Address::generate(&env);
+    let user_b = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user_a, &expert, BookingStatus::Pending);
+    vault_booking(&env, &vault, 2, &user_b, &expert, BookingStatus::Pending);
+
+    client.reserve_slot(&user_a, &expert, &(MONDAY + 10 * HOUR), &HOUR, &1);
+
+    let res = client.try_reserve_slot(&user_b, &expert, &(MONDAY + 10 * HOUR + 1_800), &HOUR, &2);
+    assert_eq!(res, Err(Ok(CalendarError::SlotOverlap)));
+
+    // Back-to-back slots do not overlap
+    client.reserve_slot(&user_b, &expert, &(MONDAY + 11 * HOUR), &HOUR, &2);
+}
+
+#[test]
+fn test_reserve_slot_frees_released_booking() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let user_a = Address::generate(&env);
+    let user_b = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user_a, &expert, BookingStatus::Pending);
+    vault_booking(&env, &vault, 2, &user_b, &expert, BookingStatus::Pending);
+
+    client.reserve_slot(&user_a, &expert, &(MONDAY + 10 * HOUR), &HOUR, &1);
+
+    // Booking 1 is cancelled in the vault, so its slot can be taken
+    vault_booking(&env, &vault, 1, &user_a, &expert, BookingStatus::Cancelled);
+    client.reserve_slot(&user_b, &expert, &(MONDAY + 10 * HOUR), &HOUR, &2);
+}
+
+#[test]
+fn test_reserve_slot_outside_availability() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let user = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Pending);
+
+    // Runs past 17:00
+    let res = client.try_reserve_slot(&user, &expert, &(MONDAY + 16 * HOUR + 1_800), &HOUR, &1);
+    assert_eq!(res, Err(Ok(CalendarError::OutsideAvailability)));
+
+    // Tuesday is not available
+    let res = client.try_reserve_slot(&user, &expert, &(MONDAY + 34 * HOUR), &HOUR, &1);
+    assert_eq!(res, Err(Ok(CalendarError::OutsideAvailability)));
+}
+
+#[test]
+fn test_reserve_slot_respects_timezone() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let user = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Pending);
+
+    // Monday 09:00-17:00 at UTC+02:00 is 07:00-15:00 UTC
+    let mut local_window = window(1, 9 * 3_600, 17 * 3_600);
+    local_window.tz_offset_seconds = 2 * 3_600;
+    client.set_availability(&expert, &vec![&env, local_window]);
+
+    let res = client.try_reserve_slot(&user, &expert, &(MONDAY + 15 * HOUR), &HOUR, &1);
+    assert_eq!(res, Err(Ok(CalendarError::OutsideAvailability)));
+
+    client.reserve_slot(&user, &expert, &(MONDAY + 7 * HOUR), &HOUR, &1);
+}
+
+#[test]
+fn test_reserve_slot_validates_vault_booking() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+    let start = MONDAY + 10 * HOUR;
+
+    let res = client.try_reserve_slot(&user, &expert, &start, &HOUR, &1);
+    assert_eq!(res, Err(Ok(CalendarError::BookingNotFound)));
+
+    vault_booking(&env, &vault, 1, &other, &expert, BookingStatus::Pending);
+    let res = client.try_reserve_slot(&user, &expert, &start, &HOUR, &1);
+    assert_eq!(res, Err(Ok(CalendarError::BookingMismatch)));
+
+    vault_booking(&env, &vault, 2, &user, &expert, BookingStatus::Complete);
+    let res = client.try_reserve_slot(&user, &expert, &start, &HOUR, &2);
+    assert_eq!(res, Err(Ok(CalendarError::BookingNotPending)));
+
+    // Slot longer than the booking pays for
+    vault_booking(&env, &vault, 3, &user, &expert, BookingStatus::Pending);
+    let res = client.try_reserve_slot(&user, &expert, &start, &(2 * HOUR), &3);
+    assert_eq!(res, Err(Ok(CalendarError::BookingMismatch)));
+}
+
+#[test]
+fn test_reserve_slot_rejects_invalid_slot() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let user = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Pending);
+    env.ledger().set_timestamp(MONDAY + 12 * HOUR);
+
+    let res = client.try_reserve_slot(&user, &expert, &(MONDAY + 10 * HOUR), &HOUR, &1);
+    assert_eq!(res, Err(Ok(CalendarError::InvalidSlot)));
+
+    let res = client.try_reserve_slot(&user, &expert, &(MONDAY + 13 * HOUR), &0, &1);
+    assert_eq!(res, Err(Ok(CalendarError::InvalidSlot)));
+}
+
+#[test]
+fn test_reserve_slot_twice_for_same_booking() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let user = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Pending);
+
+    client.reserve_slot(&user, &expert, &(MONDAY + 10 * HOUR), &HOUR, &1);
+    let res = client.try_reserve_slot(&user, &expert, &(MONDAY + 12 * HOUR), &HOUR, &1);
+    assert_eq!(res, Err(Ok(CalendarError::SlotAlreadyReserved)));
+}
+
+#[test]
+fn test_pause_blocks_reserve_slot() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let user = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Pending);
+    client.pause(&admin);
+
+    let res = client.try_reserve_slot(&user, &expert, &(MONDAY + 10 * HOUR), &HOUR, &1);
+    assert_eq!(res, Err(Ok(CalendarError::ContractPaused)));
+}
+
+// ==================== Cancellation Policy Tests ====================
+
+fn tier(min_notice_seconds: u64, refund_bps: u32) -> CancellationTier {
+    CancellationTier {
+        min_notice_seconds,
+        refund_bps,
+    }
+}
+
+/// 100% refund with 24h notice, 50% within 24h, nothing within 1h
+fn standard_policy(env: &Env) -> Vec<CancellationTier> {
+    vec![env, tier(24 * HOUR, 10_000), tier(HOUR, 5_000), tier(0, 0)]
+}
+
+#[test]
+fn test_set_and_get_cancellation_policy() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let policy = standard_policy(&env);
+    client.set_cancellation_policy(&expert, &policy);
+
+    let events = env.events().all();
+    let topic: Symbol = events
+        .last()
+        .unwrap()
+        .1
+        .get(0)
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(topic, Symbol::new(&env, "cncl_pol"));
+
+    assert_eq!(client.get_cancellation_policy(&expert), policy);
+}
+
+#[test]
+fn test_set_cancellation_policy_rejects_invalid_tiers() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let invalid = [
+        // Refund above 100%
+        vec![&env, tier(HOUR, 10_001)],
+        // Notice not strictly decreasing
+        vec![&env, tier(HOUR, 10_000), tier(HOUR, 5_000)],
+        // Shorter notice refunding more
+        vec![&env, tier(24 * HOUR, 5_000), tier(HOUR, 10_000)],
+    ];
+    for policy in invalid.iter() {
+        let res = client.try_set_cancellation_policy(&expert, policy);
+        assert_eq!(res, Err(Ok(CalendarError::InvalidCancellationPolicy)));
+    }
+}
+
+#[test]
+fn test_cancellation_refund_follows_notice() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let user = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Pending);
+    client.set_cancellation_policy(&expert, &standard_policy(&env));
+
+    // Reserve next Monday 10:00 so that all tiers can be exercised
+    let start = MONDAY + 7 * 24 * HOUR + 10 * HOUR;
+    client.reserve_slot(&user, &expert, &start, &HOUR, &1);
+
+    assert_eq!(client.get_cancellation_refund_bps(&1), 10_000);
+
+    env.ledger().set_timestamp(start - 24 * HOUR + 1);
+    assert_eq!(client.get_cancellation_refund_bps(&1), 5_000);
+
+    env.ledger().set_timestamp(start - HOUR + 1);
+    assert_eq!(client.get_cancellation_refund_bps(&1), 0);
+}
+
+#[test]
+fn test_cancellation_refund_uses_policy_at_reservation() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let user = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Pending);
+
+    // Reserved with no policy, so a later policy does not apply
+    let start = MONDAY + 10 * HOUR;
+    client.reserve_slot(&user, &expert, &start, &HOUR, &1);
+    client.set_cancellation_policy(&expert, &standard_policy(&env));
+
+    env.ledger().set_timestamp(start - 60);
+    assert_eq!(client.get_cancellation_refund_bps(&1), 10_000);
+
+    // Bookings without a reservation are fully refundable
+    assert_eq!(client.get_cancellation_refund_bps(&99), 10_000);
+}
+
+// ==================== Storage TTL Tests ====================
+
+fn persistent_ttl(env: &Env, contract: &Address, key: &crate::storage::DataKey) -> u32 {
+    env.as_contract(contract, || env.storage().persistent().get_ttl(key))
+}
+
+#[test]
+fn test_writes_extend_persistent_ttl() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let user = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Pending);
+    client.set_cancellation_policy(&expert, &standard_policy(&env));
+    client.reserve_slot(&user, &expert, &(MONDAY + 10 * HOUR), &HOUR, &1);
+
+    let keys = [
+        crate::storage::DataKey::Availability(expert.clone()),
+        crate::storage::DataKey::CancellationPolicy(expert.clone()),
+        crate::storage::DataKey::Reservation(1),
+        crate::storage::DataKey::ExpertSlots(expert.clone()),
+    ];
+    for key in keys.iter() {
+        assert!(persistent_ttl(&env, &client.address, key) >= 1_000_000);
+    }
+}
+
+#[test]
+fn test_reads_extend_persistent_ttl() {
+    let (env, admin, vault, registry, client) = setup();
+    client.init(&admin, &vault, &registry);
+    env.ledger().set_timestamp(MONDAY);
+    let expert = Address::generate(&env);
+    client.set_availability(&expert, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    let expert = Address::generate(&env);
+    let key = crate::storage::DataKey::Availability(expert.clone());
+
+    // Write the entry without a bump so it only gets the minimum TTL
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&key, &vec![&env, window(1, 9 * 3_600, 17 * 3_600)]);
+    });
+    assert!(persistent_ttl(&env, &client.address, &key) < 1_000_000);
+
+    client.get_availability(&expert);
+
+    assert!(persistent_ttl(&env, &client.address, &key) >= 1_000_000);
+}
+
+#[test]
+fn test_migrate_roles_from_pre_rbac_state() {
+    let (env, admin, vault, registry, client) = setup();
+    // A contract deployed before role storage only recorded these addresses
+    env.as_contract(&client.address, || {
+        let instance = env.storage().instance();
+        instance.set(&crate::storage::DataKey::Admin, &admin);
+        instance.set(&crate::storage::DataKey::VaultAddress, &vault);
+        instance.set(&crate::storage::DataKey::RegistryAddress, &registry);
+    });
+    assert_eq!(
+        client.try_pause(&admin),
+        Err(Ok(CalendarError::Unauthorized))
+    );
+
+    client.migrate_roles();
+    for role in access_control::ADMIN_ROLES {
+        assert!(client.has_role(&role, &admin));
+    }
+    client.pause(&admin);
+    assert_eq!(
+        client.try_migrate_roles(),
+        Err(Ok(CalendarError::AlreadyInitialized))
+    );
 }
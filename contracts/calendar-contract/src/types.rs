@@ -0,0 +1,33 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+/// A recurring weekly window during which an expert accepts sessions
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AvailabilityWindow {
+    pub day_of_week: u32,  // 0 = Sunday ... 6 = Saturday, in the expert's local time
+    pub start_second: u32, // Second of the local day the window opens
+    pub end_second: u32,   // Second of the local day the window closes (exclusive, max 86_400)
+    pub tz_offset_seconds: i32, // Expert's UTC offset in seconds (e.g. -18_000 for UTC-5)
+}
+
+/// One step of an expert's cancellation policy: cancelling at least `min_notice_seconds`
+/// before the slot starts refunds `refund_bps` of the deposit to the user.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancellationTier {
+    pub min_notice_seconds: u64,
+    pub refund_bps: u32, // 10_000 = full refund
+}
+
+/// A time slot reserved on an expert's calendar for a vault booking
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlotReservation {
+    pub booking_id: u64, // PaymentVault booking paying for this slot
+    pub user: Address,
+    pub expert: Address,
+    pub start_ts: u64,   // Ledger timestamp the slot starts
+    pub end_ts: u64,     // Ledger timestamp the slot ends (exclusive)
+    pub created_at: u64, // Ledger timestamp the reservation was made
+    pub cancellation_policy: Vec<CancellationTier>, // Expert's policy when the slot was reserved
+}
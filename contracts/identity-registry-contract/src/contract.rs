@@ -1,7 +1,18 @@
 use crate::events;
 use crate::storage;
-use crate::{error::RegistryError, types::ExpertStatus};
-use soroban_sdk::{Address, Env, String, Vec};
+use crate::{
+    error::RegistryError,
+    types::{Application, ApplicationStatus, Category, Credential, ExpertStatus, Suspension},
+};
+use access_control::Role;
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+/// Maximum number of categories an expert can hold
+const MAX_EXPERT_CATEGORIES: u32 = 5;
+/// Maximum length of a category name
+const MAX_CATEGORY_NAME_LEN: u32 = 32;
+/// Longest suspension a moderator can impose (90 days)
+const MAX_SUSPENSION_SECONDS: u64 = 90 * 24 * 60 * 60;
 
 /// Initialize the registry with an admin address
 pub fn initialize_registry(env: &Env, admin: &Address) -> Result<(), RegistryError> {
@@ -10,43 +21,69 @@ pub fn initialize_registry(env: &Env, admin: &Address) -> Result<(), RegistryErr
     }
 
     storage::set_admin(env, admin);
+    access_control::init_admin(env, admin);
 
     Ok(())
 }
 
 /// Verify an expert by setting their status to Verified (Admin only)
 /// Batch Verification
-pub fn batch_add_experts(env: Env, experts: Vec<Address>) -> Result<(), RegistryError> {
+pub fn batch_add_experts(
+    env: Env,
+    caller: &Address,
+    experts: Vec<Address>,
+) -> Result<(), RegistryError> {
     if experts.len() > 20 {
         return Err(RegistryError::ExpertVecMax);
     }
 
-    let admin = storage::get_admin(&env).ok_or(RegistryError::NotInitialized)?;
-    admin.require_auth();
+    if !storage::has_admin(&env) {
+        return Err(RegistryError::NotInitialized);
+    }
+    access_control::require_role(&env, Role::Admin, caller)?;
 
     for expert in experts {
         let status = storage::get_expert_status(&env, &expert);
-        if status == ExpertStatus::Verified {
-            return Err(RegistryError::AlreadyVerified);
+        match status {
+            ExpertStatus::Verified => return Err(RegistryError::AlreadyVerified),
+            ExpertStatus::Banned => return Err(RegistryError::AlreadyBanned),
+            ExpertStatus::Suspended => return Err(RegistryError::ExpertSuspended),
+            ExpertStatus::Unverified => {}
         }
-        // Default empty URI for batch adds
+        // Default empty URI and no categories for batch adds
         let empty_uri = String::from_str(&env, "");
-        storage::set_expert_record(&env, &expert, ExpertStatus::Verified, empty_uri, 0);
+        let no_categories = Vec::new(&env);
+        let previous = storage::get_expert_record(&env, &expert);
+        reindex_categories(&env, &expert, &previous.category_ids, &no_categories);
+        storage::set_expert_record(
+            &env,
+            &expert,
+            ExpertStatus::Verified,
+            empty_uri,
+            no_categories,
+        );
         storage::add_expert_to_index(&env, &expert);
-        events::emit_status_change(&env, expert, status, ExpertStatus::Verified, admin.clone());
+        sync_credential(&env, &expert, ExpertStatus::Verified);
+        events::emit_status_change(&env, expert, status, ExpertStatus::Verified, caller.clone());
     }
 
     Ok(())
 }
 
 /// Batch ban experts by setting their status to Banned (Admin only)
-pub fn batch_ban_experts(env: Env, experts: Vec<Address>) -> Result<(), RegistryError> {
+pub fn batch_ban_experts(
+    env: Env,
+    caller: &Address,
+    experts: Vec<Address>,
+) -> Result<(), RegistryError> {
     if experts.len() > 20 {
         return Err(RegistryError::ExpertVecMax);
     }
 
-    let admin = storage::get_admin(&env).ok_or(RegistryError::NotInitialized)?;
-    admin.require_auth();
+    if !storage::has_admin(&env) {
+        return Err(RegistryError::NotInitialized);
+    }
+    access_control::require_role(&env, Role::Admin, caller)?;
 
     for expert in experts {
         let status = storage::get_expert_status(&env, &expert);
@@ -54,18 +91,51 @@ pub fn batch_ban_experts(env: Env, experts: Vec<Address>) -> Result<(), Registry
             return Err(RegistryError::AlreadyBanned);
         }
         let existing = storage::get_expert_record(&env, &expert);
-        storage::set_expert_record(&env, &expert, ExpertStatus::Banned, existing.data_uri, existing.category_id);
-        events::emit_status_change(&env, expert, status, ExpertStatus::Banned, admin.clone());
+        storage::set_expert_record(
+            &env,
+            &expert,
+            ExpertStatus::Banned,
+            existing.data_uri,
+            existing.category_ids,
+        );
+        sync_credential(&env, &expert, ExpertStatus::Banned);
+        events::emit_status_change(&env, expert, status, ExpertStatus::Banned, caller.clone());
     }
 
     Ok(())
 }
 
+/// Nominate `new_admin` as the next admin (Admin only).
+/// Nothing changes until the nominee calls `accept_admin`; a new proposal replaces any pending one.
+pub fn propose_admin(env: &Env, new_admin: &Address) -> Result<(), RegistryError> {
+    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
+    access_control::propose_admin(env, &admin, new_admin);
+    events::emit_admin_proposed(env, admin, new_admin.clone());
+    Ok(())
+}
+
+/// Complete a pending admin transfer (pending admin only)
+pub fn accept_admin(env: &Env) -> Result<(), RegistryError> {
+    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
+    let new_admin = access_control::accept_admin(env, &admin)?;
+    storage::set_admin(env, &new_admin);
+    events::emit_admin_transferred(env, admin, new_admin);
+    Ok(())
+}
+
+/// Withdraw a pending admin proposal (Admin only)
+pub fn cancel_admin_transfer(env: &Env) -> Result<(), RegistryError> {
+    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
+    let pending_admin = access_control::cancel_admin_transfer(env, &admin)?;
+    events::emit_admin_transfer_cancelled(env, admin, pending_admin);
+    Ok(())
+}
+
 /// Add a moderator (Admin only)
 pub fn add_moderator(env: &Env, moderator: &Address) -> Result<(), RegistryError> {
     let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
     admin.require_auth();
-    storage::set_moderator(env, moderator);
+    access_control::grant_role(env, Role::Moderator, moderator, &admin);
     Ok(())
 }
 
@@ -73,40 +143,111 @@ pub fn add_moderator(env: &Env, moderator: &Address) -> Result<(), RegistryError
 pub fn remove_moderator(env: &Env, moderator: &Address) -> Result<(), RegistryError> {
     let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
     admin.require_auth();
-    storage::remove_moderator(env, moderator);
+    access_control::revoke_role(env, Role::Moderator, moderator, &admin);
+    storage::remove_legacy_moderator(env, moderator);
     Ok(())
 }
 
-pub fn verify_expert(
+/// Grant `role` to `account` (Admin role only)
+pub fn grant_role(
     env: &Env,
     caller: &Address,
-    expert: &Address,
-    data_uri: String,
-    category_id: u32,
+    role: Role,
+    account: &Address,
 ) -> Result<(), RegistryError> {
-    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
+    access_control::grant_role(env, role, account, caller);
+    Ok(())
+}
 
-    if caller == &admin {
-        admin.require_auth();
-    } else if storage::is_moderator(env, caller) {
-        caller.require_auth();
-    } else {
+/// Revoke `role` from `account` (Admin role only)
+/// The primary admin keeps the Admin role
+pub fn revoke_role(
+    env: &Env,
+    caller: &Address,
+    role: Role,
+    account: &Address,
+) -> Result<(), RegistryError> {
+    access_control::require_role(env, Role::Admin, caller)?;
+    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
+    if role == Role::Admin && *account == admin {
         return Err(RegistryError::Unauthorized);
     }
+    access_control::revoke_role(env, role, account, caller);
+    if role == Role::Moderator {
+        storage::remove_legacy_moderator(env, account);
+    }
+    Ok(())
+}
+
+/// Hand the stored admin its roles on a contract upgraded from before role storage (Admin-only).
+pub fn migrate_roles(env: &Env) -> Result<(), RegistryError> {
+    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
+    admin.require_auth();
+    if !access_control::migrate_admin(env, &admin) {
+        return Err(RegistryError::AlreadyInitialized);
+    }
+    Ok(())
+}
+
+/// Check whether `account` holds `role`, counting moderators recorded before role storage
+pub fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+    access_control::has_role(env, role, account)
+        || (role == Role::Moderator && storage::is_legacy_moderator(env, account))
+}
+
+/// Move a legacy moderator flag of `account` into role storage
+fn migrate_legacy_moderator(env: &Env, account: &Address, admin: &Address) {
+    if storage::is_legacy_moderator(env, account) {
+        access_control::grant_role(env, Role::Moderator, account, admin);
+        storage::remove_legacy_moderator(env, account);
+    }
+}
 
+pub fn verify_expert(
+    env: &Env,
+    caller: &Address,
+    expert: &Address,
+    data_uri: String,
+    category_ids: Vec<u32>,
+) -> Result<(), RegistryError> {
+    require_admin_or_moderator(env, caller)?;
+    set_verified(env, caller, expert, data_uri, category_ids)
+}
+
+/// Mark an expert Verified with the given profile, indexing and crediting them
+fn set_verified(
+    env: &Env,
+    caller: &Address,
+    expert: &Address,
+    data_uri: String,
+    category_ids: Vec<u32>,
+) -> Result<(), RegistryError> {
     let current_status = storage::get_expert_status(env, expert);
 
     if current_status == ExpertStatus::Verified {
         return Err(RegistryError::AlreadyVerified);
     }
+    if current_status == ExpertStatus::Suspended {
+        return Err(RegistryError::ExpertSuspended);
+    }
+    // Bans are lifted only through `unban_expert`
+    if current_status == ExpertStatus::Banned {
+        return Err(RegistryError::AlreadyBanned);
+    }
 
     // Validate URI length (limit ~64 chars)
     if data_uri.len() > 64 {
         return Err(RegistryError::UriTooLong);
     }
 
-    storage::set_expert_record(env, expert, ExpertStatus::Verified, data_uri, category_id);
+    validate_categories(env, &category_ids)?;
+
+    let previous = storage::get_expert_record(env, expert);
+    reindex_categories(env, expert, &previous.category_ids, &category_ids);
+    storage::set_expert_record(env, expert, ExpertStatus::Verified, data_uri, category_ids);
     storage::add_expert_to_index(env, expert);
+    sync_credential(env, expert, ExpertStatus::Verified);
 
     events::emit_status_change(
         env,
@@ -119,17 +260,13 @@ pub fn verify_expert(
     Ok(())
 }
 
-/// Ban an expert by setting their status to Banned (Admin or Moderator)
+/// Ban an expert by setting their status to Banned (Admin only)
+/// Moderators use time-bounded suspensions instead.
 pub fn ban_expert(env: &Env, caller: &Address, expert: &Address) -> Result<(), RegistryError> {
-    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
-
-    if caller == &admin {
-        admin.require_auth();
-    } else if storage::is_moderator(env, caller) {
-        caller.require_auth();
-    } else {
-        return Err(RegistryError::Unauthorized);
+    if !storage::has_admin(env) {
+        return Err(RegistryError::NotInitialized);
     }
+    access_control::require_role(env, Role::Admin, caller)?;
 
     let current_status = storage::get_expert_status(env, expert);
 
@@ -137,9 +274,16 @@ pub fn ban_expert(env: &Env, caller: &Address, expert: &Address) -> Result<(), R
         return Err(RegistryError::AlreadyBanned);
     }
 
-    // Preserve existing data_uri and category_id when banning
+    // Preserve existing data_uri and category_ids when banning
     let existing = storage::get_expert_record(env, expert);
-    storage::set_expert_record(env, expert, ExpertStatus::Banned, existing.data_uri, existing.category_id);
+    storage::set_expert_record(
+        env,
+        expert,
+        ExpertStatus::Banned,
+        existing.data_uri,
+        existing.category_ids,
+    );
+    sync_credential(env, expert, ExpertStatus::Banned);
 
     events::emit_status_change(
         env,
@@ -153,9 +297,11 @@ pub fn ban_expert(env: &Env, caller: &Address, expert: &Address) -> Result<(), R
 }
 
 /// Unban an expert by reverting their status to Verified (Admin only)
-pub fn unban_expert(env: &Env, expert: &Address) -> Result<(), RegistryError> {
-    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
-    admin.require_auth();
+pub fn unban_expert(env: &Env, caller: &Address, expert: &Address) -> Result<(), RegistryError> {
+    if !storage::has_admin(env) {
+        return Err(RegistryError::NotInitialized);
+    }
+    access_control::require_role(env, Role::Admin, caller)?;
 
     let current_status = storage::get_expert_status(env, expert);
 
@@ -163,16 +309,23 @@ pub fn unban_expert(env: &Env, expert: &Address) -> Result<(), RegistryError> {
         return Err(RegistryError::NotBanned);
     }
 
-    // Preserve existing data_uri and category_id when unbanning
+    // Preserve existing data_uri and category_ids when unbanning
     let existing = storage::get_expert_record(env, expert);
-    storage::set_expert_record(env, expert, ExpertStatus::Verified, existing.data_uri, existing.category_id);
+    storage::set_expert_record(
+        env,
+        expert,
+        ExpertStatus::Verified,
+        existing.data_uri,
+        existing.category_ids,
+    );
+    sync_credential(env, expert, ExpertStatus::Verified);
 
     events::emit_status_change(
         env,
         expert.clone(),
         current_status,
         ExpertStatus::Verified,
-        admin,
+        caller.clone(),
     );
 
     Ok(())
@@ -200,7 +353,12 @@ pub fn is_verified(env: &Env, expert: &Address) -> bool {
 }
 
 /// Allow a verified expert to update their own profile URI
-pub fn update_profile(env: &Env, expert: &Address, new_uri: String, category_id: u32) -> Result<(), RegistryError> {
+pub fn update_profile(
+    env: &Env,
+    expert: &Address,
+    new_uri: String,
+    category_ids: Vec<u32>,
+) -> Result<(), RegistryError> {
     expert.require_auth();
 
     // Validate URI length
@@ -214,7 +372,11 @@ pub fn update_profile(env: &Env, expert: &Address, new_uri: String, category_id:
     }
 
     // Update record preserving status
-    storage::set_expert_record(env, expert, status, new_uri.clone(), category_id);
+    validate_categories(env, &category_ids)?;
+
+    let previous = storage::get_expert_record(env, expert);
+    reindex_categories(env, expert, &previous.category_ids, &category_ids);
+    storage::set_expert_record(env, expert, status, new_uri.clone(), category_ids);
     events::emit_profile_updated(env, expert.clone(), new_uri);
     Ok(())
 }
@@ -223,18 +385,21 @@ pub fn update_profile(env: &Env, expert: &Address, new_uri: String, category_id:
 /// Allows admins to update multiple expert metadata URIs in a single transaction
 pub fn batch_update_profiles(
     env: &Env,
-    updates: Vec<(Address, String, u32, u32)>,
+    caller: &Address,
+    updates: Vec<(Address, String, u32, Vec<u32>)>,
 ) -> Result<(), RegistryError> {
     // Limit batch size to prevent DoS
     if updates.len() > 20 {
         return Err(RegistryError::ExpertVecMax);
     }
 
-    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
-    admin.require_auth();
+    if !storage::has_admin(env) {
+        return Err(RegistryError::NotInitialized);
+    }
+    access_control::require_role(env, Role::Admin, caller)?;
 
     for update in updates {
-        let (expert, new_uri, status_u32, category_id) = update;
+        let (expert, new_uri, status_u32, category_ids) = update;
 
         // Validate URI length
         if new_uri.len() > 64 {
@@ -246,11 +411,26 @@ pub fn batch_update_profiles(
             0 => ExpertStatus::Unverified,
             1 => ExpertStatus::Verified,
             2 => ExpertStatus::Banned,
+            // Suspensions need an end time, so they only go through `suspend_expert`
+            3 => return Err(RegistryError::InvalidSuspension),
             _ => return Err(RegistryError::NotVerified), // Invalid status value
         };
 
-        // Update the expert record
-        storage::set_expert_record(env, &expert, status, new_uri, category_id);
+        // Active suspensions must be lifted first rather than overwritten
+        if storage::get_expert_status(env, &expert) == ExpertStatus::Suspended {
+            return Err(RegistryError::ExpertSuspended);
+        }
+
+        validate_categories(env, &category_ids)?;
+
+        // Update the expert record, dropping the record of a suspension that has lapsed
+        let previous = storage::get_expert_record(env, &expert);
+        if previous.status == ExpertStatus::Suspended {
+            storage::remove_suspension(env, &expert);
+        }
+        reindex_categories(env, &expert, &previous.category_ids, &category_ids);
+        storage::set_expert_record(env, &expert, status, new_uri, category_ids);
+        sync_credential(env, &expert, status);
     }
 
     Ok(())
@@ -278,3 +458,382 @@ pub fn get_experts_paginated(env: &Env, start_index: u64, limit: u64) -> Vec<Add
     experts
 }
 
+/// Keep the expert's soulbound credential in step with their status.
+/// Verification mints it (or thaws a frozen one); any other status freezes it.
+fn sync_credential(env: &Env, expert: &Address, status: ExpertStatus) {
+    // Suspensions lift on their own, so `balance` checks the live status instead
+    if status == ExpertStatus::Suspended {
+        return;
+    }
+    let active = status == ExpertStatus::Verified;
+
+    match storage::get_credential(env, expert) {
+        None if active => {
+            let credential = Credential {
+                token_id: storage::next_credential_id(env),
+                owner: expert.clone(),
+                issued_at: env.ledger().timestamp(),
+                frozen: false,
+            };
+            storage::set_credential(env, &credential);
+            events::emit_credential_minted(env, expert.clone(), credential.token_id);
+        }
+        Some(mut credential) if credential.frozen == active => {
+            credential.frozen = !active;
+            storage::set_credential(env, &credential);
+            events::emit_credential_frozen(
+                env,
+                expert.clone(),
+                credential.token_id,
+                credential.frozen,
+            );
+        }
+        _ => {}
+    }
+}
+
+/// Credential balance: 1 while the expert holds an active credential and is verified, else 0
+pub fn balance(env: &Env, id: &Address) -> i128 {
+    match storage::get_credential(env, id) {
+        Some(credential) if !credential.frozen && is_verified(env, id) => 1,
+        _ => 0,
+    }
+}
+
+/// Metadata URI of an expert's credential (their profile data_uri)
+pub fn token_uri(env: &Env, id: &Address) -> Result<String, RegistryError> {
+    storage::get_credential(env, id).ok_or(RegistryError::ExpertNotFound)?;
+    Ok(storage::get_expert_record(env, id).data_uri)
+}
+
+/// Register a new category (Admin only)
+pub fn add_category(
+    env: &Env,
+    caller: &Address,
+    id: u32,
+    name: String,
+    parent_id: u32,
+) -> Result<(), RegistryError> {
+    if !storage::has_admin(env) {
+        return Err(RegistryError::NotInitialized);
+    }
+    access_control::require_role(env, Role::Admin, caller)?;
+
+    if id == 0 || id == parent_id || name.is_empty() || name.len() > MAX_CATEGORY_NAME_LEN {
+        return Err(RegistryError::InvalidCategory);
+    }
+    if storage::get_category(env, id).is_some() {
+        return Err(RegistryError::CategoryExists);
+    }
+    if parent_id != 0 && storage::get_category(env, parent_id).is_none() {
+        return Err(RegistryError::CategoryNotFound);
+    }
+
+    let category = Category {
+        id,
+        name,
+        parent_id,
+        active: true,
+    };
+    storage::set_category(env, &category);
+    events::emit_category_updated(env, category);
+    Ok(())
+}
+
+/// Activate or deactivate a category (Admin only)
+/// Experts already holding a deactivated category keep it; it just can't be newly assigned.
+pub fn set_category_active(
+    env: &Env,
+    caller: &Address,
+    id: u32,
+    active: bool,
+) -> Result<(), RegistryError> {
+    if !storage::has_admin(env) {
+        return Err(RegistryError::NotInitialized);
+    }
+    access_control::require_role(env, Role::Admin, caller)?;
+
+    let mut category = storage::get_category(env, id).ok_or(RegistryError::CategoryNotFound)?;
+    category.active = active;
+    storage::set_category(env, &category);
+    events::emit_category_updated(env, category);
+    Ok(())
+}
+
+/// Get every registered category
+pub fn get_categories(env: &Env) -> Vec<Category> {
+    let mut categories = Vec::new(env);
+    for id in storage::get_category_ids(env) {
+        if let Some(category) = storage::get_category(env, id) {
+            categories.push_back(category);
+        }
+    }
+    categories
+}
+
+/// Get a paginated list of experts indexed under a category
+pub fn get_experts_by_category(
+    env: &Env,
+    category_id: u32,
+    start_index: u64,
+    limit: u64,
+) -> Vec<Address> {
+    let total = storage::get_category_expert_count(env, category_id);
+    let end_index = start_index.saturating_add(limit).min(total);
+
+    let mut experts = Vec::new(env);
+    for i in start_index..end_index {
+        experts.push_back(storage::get_category_expert(env, category_id, i));
+    }
+    experts
+}
+
+/// Check that an expert's category set is bounded, unique, registered and active
+fn validate_categories(env: &Env, category_ids: &Vec<u32>) -> Result<(), RegistryError> {
+    if category_ids.len() > MAX_EXPERT_CATEGORIES {
+        return Err(RegistryError::TooManyCategories);
+    }
+
+    for (i, id) in category_ids.iter().enumerate() {
+        if category_ids.iter().skip(i + 1).any(|other| other == id) {
+            return Err(RegistryError::InvalidCategory);
+        }
+        let category = storage::get_category(env, id).ok_or(RegistryError::CategoryNotFound)?;
+        if !category.active {
+            return Err(RegistryError::CategoryInactive);
+        }
+    }
+
+    Ok(())
+}
+
+/// Move an expert between category indexes when their category set changes
+fn reindex_categories(env: &Env, expert: &Address, old_ids: &Vec<u32>, new_ids: &Vec<u32>) {
+    for id in old_ids.iter() {
+        if !new_ids.contains(id) {
+            storage::remove_expert_from_category(env, id, expert);
+        }
+    }
+    for id in new_ids.iter() {
+        if !old_ids.contains(id) {
+            storage::add_expert_to_category(env, id, expert);
+        }
+    }
+}
+
+/// Allow only the admin or a holder of the Admin or Moderator role, requiring their authorization
+fn require_admin_or_moderator(env: &Env, caller: &Address) -> Result<(), RegistryError> {
+    let admin = storage::get_admin(env).ok_or(RegistryError::NotInitialized)?;
+    migrate_legacy_moderator(env, caller, &admin);
+
+    if caller == &admin
+        || access_control::has_role(env, Role::Admin, caller)
+        || access_control::has_role(env, Role::Moderator, caller)
+    {
+        caller.require_auth();
+    } else {
+        return Err(RegistryError::Unauthorized);
+    }
+
+    Ok(())
+}
+
+/// Submit a verification application for moderator review (Expert only)
+pub fn apply_for_verification(
+    env: &Env,
+    expert: &Address,
+    data_uri: String,
+    category_ids: Vec<u32>,
+    evidence_hash: BytesN<32>,
+) -> Result<u64, RegistryError> {
+    expert.require_auth();
+
+    match storage::get_expert_status(env, expert) {
+        ExpertStatus::Verified => return Err(RegistryError::AlreadyVerified),
+        ExpertStatus::Banned => return Err(RegistryError::AlreadyBanned),
+        ExpertStatus::Suspended => return Err(RegistryError::ExpertSuspended),
+        ExpertStatus::Unverified => {}
+    }
+
+    if let Some(latest) = storage::get_latest_application(env, expert) {
+        if latest.status == ApplicationStatus::Pending {
+            return Err(RegistryError::ApplicationPending);
+        }
+    }
+
+    if data_uri.len() > 64 {
+        return Err(RegistryError::UriTooLong);
+    }
+    validate_categories(env, &category_ids)?;
+
+    let application = Application {
+        id: storage::next_application_id(env),
+        expert: expert.clone(),
+        data_uri,
+        category_ids,
+        evidence_hash,
+        status: ApplicationStatus::Pending,
+        reason_code: 0,
+        submitted_at: env.ledger().timestamp(),
+        reviewed_by: None,
+    };
+    storage::set_application(env, &application);
+    events::emit_application_event(env, &application, expert.clone());
+
+    Ok(application.id)
+}
+
+/// Approve a pending application and verify the expert (Admin or Moderator)
+pub fn approve_application(
+    env: &Env,
+    caller: &Address,
+    application_id: u64,
+) -> Result<(), RegistryError> {
+    require_admin_or_moderator(env, caller)?;
+
+    let mut application = pending_application(env, application_id)?;
+    set_verified(
+        env,
+        caller,
+        &application.expert,
+        application.data_uri.clone(),
+        application.category_ids.clone(),
+    )?;
+
+    application.status = ApplicationStatus::Approved;
+    application.reviewed_by = Some(caller.clone());
+    storage::set_application(env, &application);
+    events::emit_application_event(env, &application, caller.clone());
+
+    Ok(())
+}
+
+/// Reject a pending application with a reason code (Admin or Moderator)
+pub fn reject_application(
+    env: &Env,
+    caller: &Address,
+    application_id: u64,
+    reason_code: u32,
+) -> Result<(), RegistryError> {
+    require_admin_or_moderator(env, caller)?;
+
+    let mut application = pending_application(env, application_id)?;
+    application.status = ApplicationStatus::Rejected;
+    application.reason_code = reason_code;
+    application.reviewed_by = Some(caller.clone());
+    storage::set_application(env, &application);
+    events::emit_application_event(env, &application, caller.clone());
+
+    Ok(())
+}
+
+/// Get an application by id
+pub fn get_application(env: &Env, application_id: u64) -> Option<Application> {
+    storage::get_application(env, application_id)
+}
+
+/// Get an expert's most recent application
+pub fn get_latest_application(env: &Env, expert: &Address) -> Option<Application> {
+    storage::get_latest_application(env, expert)
+}
+
+/// Get the total number of applications ever submitted
+pub fn get_application_count(env: &Env) -> u64 {
+    storage::get_application_count(env)
+}
+
+fn pending_application(env: &Env, application_id: u64) -> Result<Application, RegistryError> {
+    let application =
+        storage::get_application(env, application_id).ok_or(RegistryError::ApplicationNotFound)?;
+    if application.status != ApplicationStatus::Pending {
+        return Err(RegistryError::ApplicationNotPending);
+    }
+    Ok(application)
+}
+
+/// Suspend a verified expert for `duration` seconds (Admin or Moderator)
+/// The expert reads as Verified again once the suspension ends.
+pub fn suspend_expert(
+    env: &Env,
+    caller: &Address,
+    expert: &Address,
+    duration: u64,
+    reason_code: u32,
+) -> Result<(), RegistryError> {
+    require_admin_or_moderator(env, caller)?;
+
+    if duration == 0 || duration > MAX_SUSPENSION_SECONDS {
+        return Err(RegistryError::InvalidSuspension);
+    }
+
+    let current_status = storage::get_expert_status(env, expert);
+    match current_status {
+        ExpertStatus::Verified => {}
+        ExpertStatus::Suspended => return Err(RegistryError::ExpertSuspended),
+        _ => return Err(RegistryError::NotVerified),
+    }
+
+    let until = env.ledger().timestamp() + duration;
+    storage::set_suspension(
+        env,
+        expert,
+        &Suspension {
+            until,
+            reason_code,
+            suspended_by: caller.clone(),
+        },
+    );
+    let existing = storage::get_expert_record(env, expert);
+    storage::set_expert_record(
+        env,
+        expert,
+        ExpertStatus::Suspended,
+        existing.data_uri,
+        existing.category_ids,
+    );
+
+    events::emit_status_change(
+        env,
+        expert.clone(),
+        current_status,
+        ExpertStatus::Suspended,
+        caller.clone(),
+    );
+    events::emit_expert_suspended(env, expert.clone(), until, reason_code, caller.clone());
+
+    Ok(())
+}
+
+/// Lift an active suspension early (Admin or Moderator)
+pub fn lift_suspension(env: &Env, caller: &Address, expert: &Address) -> Result<(), RegistryError> {
+    require_admin_or_moderator(env, caller)?;
+
+    if storage::get_expert_status(env, expert) != ExpertStatus::Suspended {
+        return Err(RegistryError::NotSuspended);
+    }
+
+    storage::remove_suspension(env, expert);
+    let existing = storage::get_expert_record(env, expert);
+    storage::set_expert_record(
+        env,
+        expert,
+        ExpertStatus::Verified,
+        existing.data_uri,
+        existing.category_ids,
+    );
+
+    events::emit_status_change(
+        env,
+        expert.clone(),
+        ExpertStatus::Suspended,
+        ExpertStatus::Verified,
+        caller.clone(),
+    );
+
+    Ok(())
+}
+
+/// Get the expert's latest suspension record
+pub fn get_suspension(env: &Env, expert: &Address) -> Option<Suspension> {
+    storage::get_suspension(env, expert)
+}
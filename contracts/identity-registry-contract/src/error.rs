@@ -1,4 +1,6 @@
+use access_control::AccessError;
 use soroban_sdk::contracterror;
+use upgrade_timelock::UpgradeError;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -20,4 +22,51 @@ pub enum RegistryError {
     UriTooLong = 9,
     NotBanned = 10,
     Unauthorized = 11,
+
+    // Credential Errors
+    NonTransferable = 12,
+
+    // Category Errors
+    CategoryNotFound = 13,
+    CategoryInactive = 14,
+    CategoryExists = 15,
+    InvalidCategory = 16,
+    TooManyCategories = 17,
+
+    // Application Errors
+    ApplicationNotFound = 18,
+    ApplicationPending = 19,
+    ApplicationNotPending = 20,
+
+    // Suspension Errors
+    ExpertSuspended = 21,
+    InvalidSuspension = 22,
+    NotSuspended = 23,
+
+    // Upgrade Errors
+    InvalidUpgradeDelay = 24,
+    NoPendingUpgrade = 25,
+    UpgradeNotReady = 26,
+
+    // Admin Transfer Errors
+    NoPendingAdmin = 27,
+}
+
+impl From<AccessError> for RegistryError {
+    fn from(err: AccessError) -> Self {
+        match err {
+            AccessError::MissingRole => RegistryError::Unauthorized,
+            AccessError::NoPendingAdmin => RegistryError::NoPendingAdmin,
+        }
+    }
+}
+
+impl From<UpgradeError> for RegistryError {
+    fn from(err: UpgradeError) -> Self {
+        match err {
+            UpgradeError::InvalidDelay => RegistryError::InvalidUpgradeDelay,
+            UpgradeError::NoPendingUpgrade => RegistryError::NoPendingUpgrade,
+            UpgradeError::UpgradeNotReady => RegistryError::UpgradeNotReady,
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::types::ExpertStatus;
+use crate::types::{Application, ApplicationStatus, Category, ExpertStatus};
 use soroban_sdk::{contracttype, Address, Env, String, Symbol};
 
 // The Event Data Structure
@@ -46,3 +46,125 @@ pub fn emit_profile_updated(env: &Env, expert: Address, new_uri: String) {
     env.events()
         .publish((Symbol::new(env, "profile_updated"),), event);
 }
+
+// Event for soulbound credential changes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CredentialUpdatedEvent {
+    pub expert: Address,
+    pub token_id: u64,
+    pub frozen: bool,
+}
+
+#[allow(deprecated)]
+pub fn emit_credential_minted(env: &Env, expert: Address, token_id: u64) {
+    let event = CredentialUpdatedEvent {
+        expert,
+        token_id,
+        frozen: false,
+    };
+    env.events()
+        .publish((Symbol::new(env, "credential_minted"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_credential_frozen(env: &Env, expert: Address, token_id: u64, frozen: bool) {
+    let event = CredentialUpdatedEvent {
+        expert,
+        token_id,
+        frozen,
+    };
+    env.events()
+        .publish((Symbol::new(env, "credential_frozen"),), event);
+}
+
+#[allow(deprecated)]
+pub fn emit_category_updated(env: &Env, category: Category) {
+    env.events()
+        .publish((Symbol::new(env, "category_updated"),), category);
+}
+
+// Event for verification application transitions
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationEvent {
+    pub application_id: u64,
+    pub expert: Address,
+    pub status: ApplicationStatus,
+    pub reason_code: u32,
+    pub actor: Address,
+}
+
+#[allow(deprecated)]
+pub fn emit_application_event(env: &Env, application: &Application, actor: Address) {
+    let topic = match application.status {
+        ApplicationStatus::Pending => "application_submitted",
+        ApplicationStatus::Approved => "application_approved",
+        ApplicationStatus::Rejected => "application_rejected",
+    };
+    let event = ApplicationEvent {
+        application_id: application.id,
+        expert: application.expert.clone(),
+        status: application.status,
+        reason_code: application.reason_code,
+        actor,
+    };
+    env.events().publish((Symbol::new(env, topic),), event);
+}
+
+// Event for suspensions
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpertSuspendedEvent {
+    pub expert: Address,
+    pub until: u64,
+    pub reason_code: u32,
+    pub moderator: Address,
+}
+
+#[allow(deprecated)]
+pub fn emit_expert_suspended(
+    env: &Env,
+    expert: Address,
+    until: u64,
+    reason_code: u32,
+    moderator: Address,
+) {
+    let event = ExpertSuspendedEvent {
+        expert,
+        until,
+        reason_code,
+        moderator,
+    };
+    env.events()
+        .publish((Symbol::new(env, "expert_suspended"),), event);
+}
+
+// Event for each step of the two-step admin transfer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferEvent {
+    pub admin: Address,
+    pub pending_admin: Address,
+}
+
+#[allow(deprecated)]
+fn emit_admin_transfer(env: &Env, topic: &str, admin: Address, pending_admin: Address) {
+    let event = AdminTransferEvent {
+        admin,
+        pending_admin,
+    };
+    env.events().publish((Symbol::new(env, topic),), event);
+}
+
+pub fn emit_admin_proposed(env: &Env, admin: Address, pending_admin: Address) {
+    emit_admin_transfer(env, "admin_proposed", admin, pending_admin);
+}
+
+pub fn emit_admin_transferred(env: &Env, admin: Address, new_admin: Address) {
+    emit_admin_transfer(env, "admin_transferred", admin, new_admin);
+}
+
+pub fn emit_admin_transfer_cancelled(env: &Env, admin: Address, pending_admin: Address) {
+    emit_admin_transfer(env, "admin_transfer_cancelled", admin, pending_admin);
+}
@@ -9,8 +9,13 @@ mod test;
 mod types;
 
 use crate::error::RegistryError;
-use crate::types::ExpertStatus;
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use crate::types::{Application, Category, ExpertStatus, Suspension};
+use access_control::Role;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
+use upgrade_timelock::PendingUpgrade;
+
+const CREDENTIAL_NAME: &str = "SkillSphere Expert Credential";
+const CREDENTIAL_SYMBOL: &str = "SSEXPERT";
 
 #[contract]
 pub struct IdentityRegistryContract;
@@ -22,6 +27,28 @@ impl IdentityRegistryContract {
         contract::initialize_registry(&env, &admin)
     }
 
+    /// Propose a new admin (Admin only)
+    /// The current admin keeps full control until the nominee calls `accept_admin`
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), RegistryError> {
+        contract::propose_admin(&env, &new_admin)
+    }
+
+    /// Accept a pending admin proposal (pending admin only)
+    /// The old admin loses its privileges at this point
+    pub fn accept_admin(env: Env) -> Result<(), RegistryError> {
+        contract::accept_admin(&env)
+    }
+
+    /// Cancel a pending admin proposal (Admin only)
+    pub fn cancel_admin_transfer(env: Env) -> Result<(), RegistryError> {
+        contract::cancel_admin_transfer(&env)
+    }
+
+    /// Get the proposed admin awaiting acceptance, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        access_control::get_pending_admin(&env)
+    }
+
     /// Add a moderator (Admin only)
     pub fn add_moderator(env: Env, moderator: Address) -> Result<(), RegistryError> {
         contract::add_moderator(&env, &moderator)
@@ -32,36 +59,136 @@ impl IdentityRegistryContract {
         contract::remove_moderator(&env, &moderator)
     }
 
+    /// Grant a role to an account (Admin role only)
+    pub fn grant_role(
+        env: Env,
+        caller: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), RegistryError> {
+        contract::grant_role(&env, &caller, role, &account)
+    }
+
+    /// Revoke a role from an account (Admin role only)
+    pub fn revoke_role(
+        env: Env,
+        caller: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), RegistryError> {
+        contract::revoke_role(&env, &caller, role, &account)
+    }
+
+    /// Grant the admin roles to the stored admin after upgrading a contract deployed
+    /// before role storage existed (Admin-only)
+    pub fn migrate_roles(env: Env) -> Result<(), RegistryError> {
+        contract::migrate_roles(&env)
+    }
+
+    /// Check whether an account holds a role
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        contract::has_role(&env, role, &account)
+    }
+
+    /// Announce a WASM upgrade (Upgrader role only)
+    /// It becomes executable after the upgrade delay, giving users time to exit
+    pub fn schedule_upgrade(
+        env: Env,
+        caller: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), RegistryError> {
+        upgrade_timelock::schedule_as_upgrader(&env, &caller, new_wasm_hash)
+    }
+
+    /// Apply the scheduled upgrade once its delay has elapsed (Upgrader role only)
+    pub fn execute_upgrade(env: Env, caller: Address) -> Result<(), RegistryError> {
+        upgrade_timelock::execute_as_upgrader(&env, &caller)
+    }
+
+    /// Cancel the scheduled upgrade (Upgrader role only)
+    pub fn cancel_upgrade(env: Env, caller: Address) -> Result<(), RegistryError> {
+        upgrade_timelock::cancel_as_upgrader(&env, &caller)
+    }
+
+    /// Set the delay in seconds between scheduling and executing an upgrade (Admin role only)
+    pub fn set_upgrade_delay(env: Env, caller: Address, delay: u64) -> Result<(), RegistryError> {
+        upgrade_timelock::set_delay_as_admin(&env, &caller, delay)
+    }
+
+    /// Get the scheduled upgrade, if any
+    pub fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade> {
+        upgrade_timelock::get_pending(&env)
+    }
+
+    /// Get the delay in seconds between scheduling and executing an upgrade
+    pub fn get_upgrade_delay(env: Env) -> u64 {
+        upgrade_timelock::get_delay(&env)
+    }
+
     /// Batch Add an expert to the whitelist (Admin only)
-    pub fn batch_add_experts(env: Env, experts: Vec<Address>) -> Result<(), RegistryError> {
-        contract::batch_add_experts(env, experts)
+    pub fn batch_add_experts(
+        env: Env,
+        caller: Address,
+        experts: Vec<Address>,
+    ) -> Result<(), RegistryError> {
+        contract::batch_add_experts(env, &caller, experts)
     }
 
     /// Batch ban experts and revoke their verification status (Admin only)
-    pub fn batch_ban_experts(env: Env, experts: Vec<Address>) -> Result<(), RegistryError> {
-        contract::batch_ban_experts(env, experts)
+    pub fn batch_ban_experts(
+        env: Env,
+        caller: Address,
+        experts: Vec<Address>,
+    ) -> Result<(), RegistryError> {
+        contract::batch_ban_experts(env, &caller, experts)
     }
 
     /// Add an expert to the whitelist (Admin or Moderator)
-    /// Also saves a profile data_uri reference (e.g., ipfs://...)
+    /// Also saves a profile data_uri reference (e.g., ipfs://...) and up to 5 active category ids
     pub fn add_expert(
         env: Env,
         caller: Address,
         expert: Address,
         data_uri: String,
-        category_id: u32,
+        category_ids: Vec<u32>,
     ) -> Result<(), RegistryError> {
-        contract::verify_expert(&env, &caller, &expert, data_uri, category_id)
+        contract::verify_expert(&env, &caller, &expert, data_uri, category_ids)
     }
 
-    /// Ban an expert and revoke their verification status (Admin or Moderator)
+    /// Permanently ban an expert and revoke their verification status (Admin only)
     pub fn ban_expert(env: Env, caller: Address, expert: Address) -> Result<(), RegistryError> {
         contract::ban_expert(&env, &caller, &expert)
     }
 
+    /// Suspend a verified expert for `duration` seconds with a reason code (Admin or Moderator)
+    /// `is_verified` returns true again automatically once the suspension ends (max 90 days)
+    pub fn suspend_expert(
+        env: Env,
+        caller: Address,
+        expert: Address,
+        duration: u64,
+        reason_code: u32,
+    ) -> Result<(), RegistryError> {
+        contract::suspend_expert(&env, &caller, &expert, duration, reason_code)
+    }
+
+    /// Lift an active suspension early (Admin or Moderator)
+    pub fn lift_suspension(
+        env: Env,
+        caller: Address,
+        expert: Address,
+    ) -> Result<(), RegistryError> {
+        contract::lift_suspension(&env, &caller, &expert)
+    }
+
+    /// Get the expert's latest suspension record
+    pub fn get_suspension(env: Env, expert: Address) -> Option<Suspension> {
+        contract::get_suspension(&env, &expert)
+    }
+
     /// Unban an expert and restore their verification status (Admin only)
-    pub fn unban_expert(env: Env, expert: Address) -> Result<(), RegistryError> {
-        contract::unban_expert(&env, &expert)
+    pub fn unban_expert(env: Env, caller: Address, expert: Address) -> Result<(), RegistryError> {
+        contract::unban_expert(&env, &caller, &expert)
     }
 
     /// Get the total number of verified experts ever added to the directory
@@ -85,18 +212,25 @@ impl IdentityRegistryContract {
         contract::is_verified(&env, &expert)
     }
 
-    /// Allow a verified expert to update their own profile URI
-    pub fn update_profile(env: Env, expert: Address, new_uri: String, category_id: u32) -> Result<(), RegistryError> {
-        contract::update_profile(&env, &expert, new_uri, category_id)
+    /// Allow a verified expert to update their own profile URI and categories
+    pub fn update_profile(
+        env: Env,
+        expert: Address,
+        new_uri: String,
+        category_ids: Vec<u32>,
+    ) -> Result<(), RegistryError> {
+        contract::update_profile(&env, &expert, new_uri, category_ids)
     }
 
     /// Batch update expert profiles (Admin only)
     /// Allows admins to update multiple expert metadata URIs in a single transaction
+    /// Experts under an active suspension are rejected; use `lift_suspension` first
     pub fn batch_update_profiles(
         env: Env,
-        updates: Vec<(Address, String, u32, u32)>,
+        caller: Address,
+        updates: Vec<(Address, String, u32, Vec<u32>)>,
     ) -> Result<(), RegistryError> {
-        contract::batch_update_profiles(&env, updates)
+        contract::batch_update_profiles(&env, &caller, updates)
     }
 
     /// Get a paginated list of experts
@@ -105,4 +239,151 @@ impl IdentityRegistryContract {
         contract::get_experts_paginated(&env, start_index, limit)
     }
 
+    /// Register a new category (Admin only)
+    /// `parent_id` is 0 for top-level categories, otherwise an existing category id
+    pub fn add_category(
+        env: Env,
+        caller: Address,
+        id: u32,
+        name: String,
+        parent_id: u32,
+    ) -> Result<(), RegistryError> {
+        contract::add_category(&env, &caller, id, name, parent_id)
+    }
+
+    /// Activate or deactivate a category (Admin only)
+    pub fn set_category_active(
+        env: Env,
+        caller: Address,
+        id: u32,
+        active: bool,
+    ) -> Result<(), RegistryError> {
+        contract::set_category_active(&env, &caller, id, active)
+    }
+
+    /// Get every registered category
+    pub fn get_categories(env: Env) -> Vec<Category> {
+        contract::get_categories(&env)
+    }
+
+    /// Get a paginated list of experts holding a category
+    /// Returns a vector of expert addresses from start_index to start_index + limit
+    pub fn get_experts_by_category(
+        env: Env,
+        category_id: u32,
+        start_index: u64,
+        limit: u64,
+    ) -> Vec<Address> {
+        contract::get_experts_by_category(&env, category_id, start_index, limit)
+    }
+
+    /// Apply for verification (Expert only)
+    /// Creates a Pending application for moderators to review; returns its id
+    pub fn apply_for_verification(
+        env: Env,
+        expert: Address,
+        data_uri: String,
+        category_ids: Vec<u32>,
+        evidence_hash: BytesN<32>,
+    ) -> Result<u64, RegistryError> {
+        contract::apply_for_verification(&env, &expert, data_uri, category_ids, evidence_hash)
+    }
+
+    /// Approve a pending application and verify the applicant (Admin or Moderator)
+    pub fn approve_application(
+        env: Env,
+        caller: Address,
+        application_id: u64,
+    ) -> Result<(), RegistryError> {
+        contract::approve_application(&env, &caller, application_id)
+    }
+
+    /// Reject a pending application with a reason code (Admin or Moderator)
+    pub fn reject_application(
+        env: Env,
+        caller: Address,
+        application_id: u64,
+        reason_code: u32,
+    ) -> Result<(), RegistryError> {
+        contract::reject_application(&env, &caller, application_id, reason_code)
+    }
+
+    /// Get an application by id
+    pub fn get_application(env: Env, application_id: u64) -> Option<Application> {
+        contract::get_application(&env, application_id)
+    }
+
+    /// Get an expert's most recent application
+    pub fn get_latest_application(env: Env, expert: Address) -> Option<Application> {
+        contract::get_latest_application(&env, &expert)
+    }
+
+    /// Get the total number of applications ever submitted (ids run from 0 to count - 1)
+    pub fn get_application_count(env: Env) -> u64 {
+        contract::get_application_count(&env)
+    }
+
+    // ── Soulbound credential (SEP-41 style read interface) ──────────────────
+
+    /// Name of the expert credential token
+    pub fn name(env: Env) -> String {
+        String::from_str(&env, CREDENTIAL_NAME)
+    }
+
+    /// Symbol of the expert credential token
+    pub fn symbol(env: Env) -> String {
+        String::from_str(&env, CREDENTIAL_SYMBOL)
+    }
+
+    /// Credentials are indivisible
+    pub fn decimals(_env: Env) -> u32 {
+        0
+    }
+
+    /// 1 if `id` holds an active expert credential, 0 otherwise (never verified, or banned)
+    pub fn balance(env: Env, id: Address) -> i128 {
+        contract::balance(&env, &id)
+    }
+
+    /// Metadata URI of the credential held by `id`
+    pub fn token_uri(env: Env, id: Address) -> Result<String, RegistryError> {
+        contract::token_uri(&env, &id)
+    }
+
+    /// Credentials are soulbound: spending allowances are always zero
+    pub fn allowance(_env: Env, _from: Address, _spender: Address) -> i128 {
+        0
+    }
+
+    /// Always fails: credentials are soulbound
+    pub fn transfer(
+        _env: Env,
+        _from: Address,
+        _to: Address,
+        _amount: i128,
+    ) -> Result<(), RegistryError> {
+        Err(RegistryError::NonTransferable)
+    }
+
+    /// Always fails: credentials are soulbound
+    pub fn transfer_from(
+        _env: Env,
+        _spender: Address,
+        _from: Address,
+        _to: Address,
+        _amount: i128,
+    ) -> Result<(), RegistryError> {
+        Err(RegistryError::NonTransferable)
+    }
+
+    /// Always fails: credentials are soulbound
+    pub fn approve(
+        _env: Env,
+        _from: Address,
+        _spender: Address,
+        _amount: i128,
+        _expiration_ledger: u32,
+    ) -> Result<(), RegistryError> {
+        Err(RegistryError::NonTransferable)
+    }
 }
@@ -1,5 +1,5 @@
-use crate::types::{ExpertRecord, ExpertStatus};
-use soroban_sdk::{contracttype, Address, Env, String};
+use crate::types::{Application, Category, Credential, ExpertRecord, ExpertStatus, Suspension};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
 // 1. Data Keys
 #[contracttype]
@@ -9,7 +9,18 @@ pub enum DataKey {
     Expert(Address),
     VerifiedExpertIndex(u64),
     TotalVerifiedCount,
-    Moderator(Address),
+    Moderator(Address), // legacy moderator flag, superseded by the Moderator role
+    Credential(Address),
+    CredentialCount,
+    Category(u32),
+    CategoryIds,
+    CategoryExpert(u32, u64),             // (category, index) -> expert
+    CategoryExpertCount(u32),             // category -> number of indexed experts
+    CategoryExpertPosition(u32, Address), // (category, expert) -> index
+    Application(u64),
+    LatestApplication(Address), // expert -> id of their most recent application
+    ApplicationCount,
+    Suspension(Address),
 }
 
 // Constants for TTL (Time To Live)
@@ -41,25 +52,18 @@ pub fn get_admin(env: &Env) -> Option<Address> {
     env.storage().instance().get(&DataKey::Admin)
 }
 
-// ... [Moderator Helpers] ...
+// ... [Legacy Moderator Helpers] ...
 
-/// Check if an address is a moderator
-pub fn is_moderator(env: &Env, address: &Address) -> bool {
+/// Check if an address was made a moderator before moderators moved to role storage
+pub fn is_legacy_moderator(env: &Env, address: &Address) -> bool {
     env.storage()
         .instance()
         .get(&DataKey::Moderator(address.clone()))
         .unwrap_or(false)
 }
 
-/// Set an address as a moderator
-pub fn set_moderator(env: &Env, address: &Address) {
-    env.storage()
-        .instance()
-        .set(&DataKey::Moderator(address.clone()), &true);
-}
-
-/// Remove an address from moderators
-pub fn remove_moderator(env: &Env, address: &Address) {
+/// Drop the legacy moderator flag of an address
+pub fn remove_legacy_moderator(env: &Env, address: &Address) {
     env.storage()
         .instance()
         .remove(&DataKey::Moderator(address.clone()));
@@ -68,14 +72,20 @@ pub fn remove_moderator(env: &Env, address: &Address) {
 // ... [Expert Helpers] ...
 
 /// Set the expert record with status, data_uri and timestamp
-pub fn set_expert_record(env: &Env, expert: &Address, status: ExpertStatus, data_uri: String, category_id: u32) {
+pub fn set_expert_record(
+    env: &Env,
+    expert: &Address,
+    status: ExpertStatus,
+    data_uri: String,
+    category_ids: Vec<u32>,
+) {
     let key = DataKey::Expert(expert.clone());
 
     let record = ExpertRecord {
         status,
         updated_at: env.ledger().timestamp(),
         data_uri,
-        category_id,
+        category_ids,
     };
 
     // 1. Save the data
@@ -108,13 +118,22 @@ pub fn get_expert_record(env: &Env, expert: &Address) -> ExpertRecord {
             status: ExpertStatus::Unverified,
             updated_at: 0,
             data_uri: String::from_str(env, ""),
-            category_id: 0,
+            category_ids: Vec::new(env),
         })
 }
 
-/// Get the expert status
+/// Get the effective expert status
+/// A suspension whose end time has passed reads as Verified without any write.
 pub fn get_expert_status(env: &Env, expert: &Address) -> ExpertStatus {
-    get_expert_record(env, expert).status
+    let status = get_expert_record(env, expert).status;
+    if status == ExpertStatus::Suspended {
+        let lapsed = get_suspension(env, expert)
+            .is_none_or(|suspension| env.ledger().timestamp() >= suspension.until);
+        if lapsed {
+            return ExpertStatus::Verified;
+        }
+    }
+    status
 }
 
 // ... [Expert Directory Index Helpers] ...
@@ -156,3 +175,200 @@ pub fn get_expert_by_index(env: &Env, index: u64) -> Address {
         .get(&DataKey::VerifiedExpertIndex(index))
         .expect("Index out of bounds")
 }
+
+// ... [Credential Helpers] ...
+
+/// Get the soulbound credential held by an expert, if any
+pub fn get_credential(env: &Env, expert: &Address) -> Option<Credential> {
+    let key = DataKey::Credential(expert.clone());
+    let credential = env.storage().persistent().get(&key);
+    if credential.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+    }
+    credential
+}
+
+/// Save an expert's soulbound credential
+pub fn set_credential(env: &Env, credential: &Credential) {
+    let key = DataKey::Credential(credential.owner.clone());
+    env.storage().persistent().set(&key, credential);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Allocate the next credential token id
+pub fn next_credential_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::CredentialCount)
+        .unwrap_or(0u64);
+    env.storage()
+        .instance()
+        .set(&DataKey::CredentialCount, &(id + 1));
+    id
+}
+
+// ... [Category Helpers] ...
+
+/// Get a category from the registry
+pub fn get_category(env: &Env, id: u32) -> Option<Category> {
+    env.storage().persistent().get(&DataKey::Category(id))
+}
+
+/// Save a category, adding new ids to the category list
+pub fn set_category(env: &Env, category: &Category) {
+    let key = DataKey::Category(category.id);
+    if !env.storage().persistent().has(&key) {
+        let mut ids = get_category_ids(env);
+        ids.push_back(category.id);
+        env.storage().instance().set(&DataKey::CategoryIds, &ids);
+    }
+    env.storage().persistent().set(&key, category);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Get the ids of every registered category
+pub fn get_category_ids(env: &Env) -> Vec<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::CategoryIds)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Get the number of experts indexed under a category
+pub fn get_category_expert_count(env: &Env, category_id: u32) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CategoryExpertCount(category_id))
+        .unwrap_or(0u64)
+}
+
+/// Get the expert at the given index of a category
+pub fn get_category_expert(env: &Env, category_id: u32, index: u64) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CategoryExpert(category_id, index))
+        .expect("Index out of bounds")
+}
+
+/// Append an expert to a category index
+pub fn add_expert_to_category(env: &Env, category_id: u32, expert: &Address) {
+    let count = get_category_expert_count(env, category_id);
+    set_category_slot(env, category_id, count, expert);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CategoryExpertCount(category_id), &(count + 1));
+}
+
+/// Remove an expert from a category index by moving the last entry into its slot
+pub fn remove_expert_from_category(env: &Env, category_id: u32, expert: &Address) {
+    let position_key = DataKey::CategoryExpertPosition(category_id, expert.clone());
+    let Some(index) = env.storage().persistent().get::<_, u64>(&position_key) else {
+        return;
+    };
+
+    let last = get_category_expert_count(env, category_id) - 1;
+    if index != last {
+        let moved = get_category_expert(env, category_id, last);
+        set_category_slot(env, category_id, index, &moved);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CategoryExpert(category_id, last));
+    env.storage().persistent().remove(&position_key);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CategoryExpertCount(category_id), &last);
+}
+
+fn set_category_slot(env: &Env, category_id: u32, index: u64, expert: &Address) {
+    let slot_key = DataKey::CategoryExpert(category_id, index);
+    let position_key = DataKey::CategoryExpertPosition(category_id, expert.clone());
+    env.storage().persistent().set(&slot_key, expert);
+    env.storage().persistent().set(&position_key, &index);
+    env.storage()
+        .persistent()
+        .extend_ttl(&slot_key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+    env.storage()
+        .persistent()
+        .extend_ttl(&position_key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+// ... [Application Helpers] ...
+
+/// Allocate the next application id
+pub fn next_application_id(env: &Env) -> u64 {
+    let id = get_application_count(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::ApplicationCount, &(id + 1));
+    id
+}
+
+/// Get the total number of applications ever submitted
+pub fn get_application_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ApplicationCount)
+        .unwrap_or(0u64)
+}
+
+/// Save an application and mark it as the expert's latest
+pub fn set_application(env: &Env, application: &Application) {
+    let key = DataKey::Application(application.id);
+    let latest_key = DataKey::LatestApplication(application.expert.clone());
+    env.storage().persistent().set(&key, application);
+    env.storage().persistent().set(&latest_key, &application.id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+    env.storage()
+        .persistent()
+        .extend_ttl(&latest_key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Get an application by id
+pub fn get_application(env: &Env, id: u64) -> Option<Application> {
+    env.storage().persistent().get(&DataKey::Application(id))
+}
+
+/// Get the expert's most recent application, if any
+pub fn get_latest_application(env: &Env, expert: &Address) -> Option<Application> {
+    let id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::LatestApplication(expert.clone()))?;
+    get_application(env, id)
+}
+
+// ... [Suspension Helpers] ...
+
+/// Get the expert's latest suspension, if any
+pub fn get_suspension(env: &Env, expert: &Address) -> Option<Suspension> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Suspension(expert.clone()))
+}
+
+/// Save the expert's suspension
+pub fn set_suspension(env: &Env, expert: &Address, suspension: &Suspension) {
+    let key = DataKey::Suspension(expert.clone());
+    env.storage().persistent().set(&key, suspension);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+/// Remove the expert's suspension
+pub fn remove_suspension(env: &Env, expert: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Suspension(expert.clone()));
+}
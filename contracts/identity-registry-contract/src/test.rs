@@ -3,11 +3,15 @@
 extern crate std;
 
 use crate::error::RegistryError;
-use crate::{storage, types::ExpertStatus};
+use crate::{
+    storage,
+    types::{ApplicationStatus, ExpertStatus},
+};
 use crate::{IdentityRegistryContract, IdentityRegistryContractClient};
-use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation, Events};
+use access_control::Role;
+use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation, Events, Ledger};
 use soroban_sdk::{
-    testutils::Address as _, vec, Address, Env, IntoVal, String, Symbol, TryIntoVal,
+    testutils::Address as _, vec, Address, BytesN, Env, IntoVal, String, Symbol, TryIntoVal, Vec,
 };
 
 #[test]
@@ -41,7 +45,7 @@ fn test_data_uri_persisted_on_verify() {
     let uri = String::from_str(&env, "ipfs://persisted");
 
     client.init(&admin);
-    client.add_expert(&admin, &expert, &uri, &0u32);
+    client.add_expert(&admin, &expert, &uri, &vec![&env]);
 
     // Read storage as contract and assert data_uri persisted
     env.as_contract(&contract_id, || {
@@ -64,10 +68,10 @@ fn test_update_profile_updates_uri_and_emits_event() {
     let uri2 = String::from_str(&env, "ipfs://updated");
 
     client.init(&admin);
-    client.add_expert(&admin, &expert, &uri1, &0u32);
+    client.add_expert(&admin, &expert, &uri1, &vec![&env]);
 
     // Update profile URI
-    client.update_profile(&expert, &uri2, &1u32);
+    client.update_profile(&expert, &uri2, &vec![&env]);
 
     // Assert record updated
     env.as_contract(&contract_id, || {
@@ -92,18 +96,18 @@ fn test_update_profile_rejections() {
 
     // NotVerified when updating without being verified
     let new_uri = String::from_str(&env, "ipfs://new");
-    let res = client.try_update_profile(&unverified, &new_uri, &0u32);
+    let res = client.try_update_profile(&unverified, &new_uri, &vec![&env]);
     assert_eq!(res, Err(Ok(RegistryError::NotVerified)));
 
     // Verify then try overlong uri
     let expert = Address::generate(&env);
     let ok_uri = String::from_str(&env, "ipfs://ok");
-    client.add_expert(&admin, &expert, &ok_uri, &0u32);
+    client.add_expert(&admin, &expert, &ok_uri, &vec![&env]);
 
     // Build >64 length string
     let long_str = "a".repeat(65);
     let long_uri = String::from_str(&env, long_str.as_str());
-    let res2 = client.try_update_profile(&expert, &long_uri, &0u32);
+    let res2 = client.try_update_profile(&expert, &long_uri, &vec![&env]);
     assert_eq!(res2, Err(Ok(RegistryError::UriTooLong)));
 }
 
@@ -122,7 +126,7 @@ fn test_batch_verification_no_admin() {
         Address::generate(&env),
     ];
 
-    client.batch_add_experts(&experts);
+    client.batch_add_experts(&Address::generate(&env), &experts);
 }
 
 #[test]
@@ -151,7 +155,7 @@ fn test_batch_verification_check_status() {
         e5.clone(),
     ];
 
-    client.batch_add_experts(&experts);
+    client.batch_add_experts(&admin, &experts);
 
     env.as_contract(&contract_id, || {
         assert_eq!(
@@ -222,7 +226,7 @@ fn test_batch_verification_max_vec() {
         e4.clone(),
     ];
 
-    client.batch_add_experts(&experts);
+    client.batch_add_experts(&admin, &experts);
 }
 
 #[test]
@@ -239,7 +243,8 @@ fn test_add_expert() {
     client.init(&admin);
 
     let data_uri = String::from_str(&env, "ipfs://profile1");
-    let res = client.try_add_expert(&admin, &expert, &data_uri, &0u32);
+    let no_categories: Vec<u32> = vec![&env];
+    let res = client.try_add_expert(&admin, &expert, &data_uri, &no_categories);
     assert!(res.is_ok());
 
     assert_eq!(
@@ -250,7 +255,13 @@ fn test_add_expert() {
                 function: AuthorizedFunction::Contract((
                     contract_id.clone(),
                     Symbol::new(&env, "add_expert"),
-                    (admin.clone(), expert.clone(), data_uri.clone(), 0u32).into_val(&env)
+                    (
+                        admin.clone(),
+                        expert.clone(),
+                        data_uri.clone(),
+                        no_categories
+                    )
+                        .into_val(&env)
                 )),
                 sub_invocations: std::vec![]
             }
@@ -272,7 +283,7 @@ fn test_add_expert_unauthorized() {
     client.init(&admin);
     let data_uri = String::from_str(&env, "ipfs://unauth");
     // admin is the caller but no auth is mocked — should panic
-    client.add_expert(&admin, &expert, &data_uri, &0u32);
+    client.add_expert(&admin, &expert, &data_uri, &vec![&env]);
 }
 
 #[test]
@@ -288,7 +299,7 @@ fn test_expert_status_changed_event() {
 
     client.init(&admin);
     let data_uri = String::from_str(&env, "ipfs://event");
-    client.add_expert(&admin, &expert, &data_uri, &0u32);
+    client.add_expert(&admin, &expert, &data_uri, &vec![&env]);
 
     let events = env.events().all();
     let event = events.last().unwrap();
@@ -314,7 +325,7 @@ fn test_ban_expert() {
     // Verify the expert first
     env.mock_all_auths();
     let data_uri = String::from_str(&env, "ipfs://ban");
-    client.add_expert(&admin, &expert, &data_uri, &0u32);
+    client.add_expert(&admin, &expert, &data_uri, &vec![&env]);
 
     // Verify status is Verified
     let status = client.get_status(&expert);
@@ -346,7 +357,7 @@ fn test_ban_expert_unauthorized() {
 
     env.mock_all_auths();
     let data_uri = String::from_str(&env, "ipfs://ban-unauth");
-    client.add_expert(&admin, &expert, &data_uri, &0u32);
+    client.add_expert(&admin, &expert, &data_uri, &vec![&env]);
 
     env.mock_all_auths_allowing_non_root_auth();
 
@@ -401,9 +412,9 @@ fn test_ban_expert_workflow() {
     let uri1 = String::from_str(&env, "ipfs://u1");
     let uri2 = String::from_str(&env, "ipfs://u2");
     let uri3 = String::from_str(&env, "ipfs://u3");
-    client.add_expert(&admin, &expert1, &uri1, &0u32);
-    client.add_expert(&admin, &expert2, &uri2, &0u32);
-    client.add_expert(&admin, &expert3, &uri3, &0u32);
+    client.add_expert(&admin, &expert1, &uri1, &vec![&env]);
+    client.add_expert(&admin, &expert2, &uri2, &vec![&env]);
+    client.add_expert(&admin, &expert3, &uri3, &vec![&env]);
 
     // Check all are verified
     assert_eq!(client.get_status(&expert1), ExpertStatus::Verified);
@@ -462,7 +473,7 @@ fn test_complete_expert_lifecycle() {
 
     // 2. Verify the expert
     let data_uri = String::from_str(&env, "ipfs://life");
-    client.add_expert(&admin, &expert, &data_uri, &0u32);
+    client.add_expert(&admin, &expert, &data_uri, &vec![&env]);
     assert_eq!(client.get_status(&expert), ExpertStatus::Verified);
 
     // 3. Ban the expert
@@ -483,19 +494,19 @@ fn test_getters() {
 
     // Test 1: Check is_verified on a random address (should be false)
     let random_address = Address::generate(&env);
-    assert_eq!(client.is_verified(&random_address), false);
+    assert!(!client.is_verified(&random_address));
     assert_eq!(client.get_status(&random_address), ExpertStatus::Unverified);
 
     // Test 2: Verify an expert and check is_verified (should be true)
     let expert = Address::generate(&env);
     let data_uri = String::from_str(&env, "ipfs://getters");
-    client.add_expert(&admin, &expert, &data_uri, &0u32);
-    assert_eq!(client.is_verified(&expert), true);
+    client.add_expert(&admin, &expert, &data_uri, &vec![&env]);
+    assert!(client.is_verified(&expert));
     assert_eq!(client.get_status(&expert), ExpertStatus::Verified);
 
     // Test 3: Ban the expert and check is_verified (should be false)
     client.ban_expert(&admin, &expert);
-    assert_eq!(client.is_verified(&expert), false);
+    assert!(!client.is_verified(&expert));
     assert_eq!(client.get_status(&expert), ExpertStatus::Banned);
 }
 
@@ -518,9 +529,9 @@ fn test_expert_directory_enumeration() {
     let uri1 = String::from_str(&env, "ipfs://e1");
     let uri2 = String::from_str(&env, "ipfs://e2");
     let uri3 = String::from_str(&env, "ipfs://e3");
-    client.add_expert(&admin, &expert1, &uri1, &0u32);
-    client.add_expert(&admin, &expert2, &uri2, &0u32);
-    client.add_expert(&admin, &expert3, &uri3, &0u32);
+    client.add_expert(&admin, &expert1, &uri1, &vec![&env]);
+    client.add_expert(&admin, &expert2, &uri2, &vec![&env]);
+    client.add_expert(&admin, &expert3, &uri3, &vec![&env]);
 
     // Total should be 3
     assert_eq!(client.get_total_experts(), 3u64);
@@ -545,13 +556,13 @@ fn test_expert_directory_no_duplicates_on_reverify() {
     client.init(&admin);
 
     let uri = String::from_str(&env, "ipfs://expert");
-    client.add_expert(&admin, &expert, &uri, &0u32);
+    client.add_expert(&admin, &expert, &uri, &vec![&env]);
 
     // Total is 1
     assert_eq!(client.get_total_experts(), 1u64);
 
     // Re-verifying an already verified expert returns AlreadyVerified
-    let result = client.try_add_expert(&admin, &expert, &uri, &0u32);
+    let result = client.try_add_expert(&admin, &expert, &uri, &vec![&env]);
     assert_eq!(result, Err(Ok(RegistryError::AlreadyVerified)));
 
     // Total remains 1 — no duplicate in the index
@@ -574,7 +585,7 @@ fn test_expert_directory_via_batch_add() {
     client.init(&admin);
 
     let experts = vec![&env, expert1.clone(), expert2.clone(), expert3.clone()];
-    client.batch_add_experts(&experts);
+    client.batch_add_experts(&admin, &experts);
 
     // Total should be 3
     assert_eq!(client.get_total_experts(), 3u64);
@@ -609,11 +620,11 @@ fn test_batch_update_profiles() {
     let uri4 = String::from_str(&env, "ipfs://original4");
     let uri5 = String::from_str(&env, "ipfs://original5");
 
-    client.add_expert(&admin, &expert1, &uri1, &0u32);
-    client.add_expert(&admin, &expert2, &uri2, &0u32);
-    client.add_expert(&admin, &expert3, &uri3, &0u32);
-    client.add_expert(&admin, &expert4, &uri4, &0u32);
-    client.add_expert(&admin, &expert5, &uri5, &0u32);
+    client.add_expert(&admin, &expert1, &uri1, &vec![&env]);
+    client.add_expert(&admin, &expert2, &uri2, &vec![&env]);
+    client.add_expert(&admin, &expert3, &uri3, &vec![&env]);
+    client.add_expert(&admin, &expert4, &uri4, &vec![&env]);
+    client.add_expert(&admin, &expert5, &uri5, &vec![&env]);
 
     // Prepare batch updates with new URIs
     let new_uri1 = String::from_str(&env, "ipfs://updated1");
@@ -624,15 +635,15 @@ fn test_batch_update_profiles() {
 
     let updates = vec![
         &env,
-        (expert1.clone(), new_uri1.clone(), 1u32, 0u32),
-        (expert2.clone(), new_uri2.clone(), 1u32, 0u32),
-        (expert3.clone(), new_uri3.clone(), 1u32, 0u32),
-        (expert4.clone(), new_uri4.clone(), 1u32, 0u32),
-        (expert5.clone(), new_uri5.clone(), 1u32, 0u32),
+        (expert1.clone(), new_uri1.clone(), 1u32, vec![&env]),
+        (expert2.clone(), new_uri2.clone(), 1u32, vec![&env]),
+        (expert3.clone(), new_uri3.clone(), 1u32, vec![&env]),
+        (expert4.clone(), new_uri4.clone(), 1u32, vec![&env]),
+        (expert5.clone(), new_uri5.clone(), 1u32, vec![&env]),
     ];
 
     // Execute batch update
-    client.batch_update_profiles(&updates);
+    client.batch_update_profiles(&admin, &updates);
 
     // Verify all 5 profiles have the new URIs
     env.as_contract(&contract_id, || {
@@ -674,11 +685,11 @@ fn test_batch_update_profiles_max_vec() {
     for _ in 0..21 {
         let expert = Address::generate(&env);
         let uri = String::from_str(&env, "ipfs://test");
-        updates.push_back((expert, uri, 1u32, 0u32));
+        updates.push_back((expert, uri, 1u32, vec![&env]));
     }
 
     // This should fail with ExpertVecMax error
-    client.batch_update_profiles(&updates);
+    client.batch_update_profiles(&admin, &updates);
 }
 
 #[test]
@@ -694,16 +705,16 @@ fn test_batch_update_profiles_uri_too_long() {
 
     let expert = Address::generate(&env);
     let uri = String::from_str(&env, "ipfs://initial");
-    client.add_expert(&admin, &expert, &uri, &0u32);
+    client.add_expert(&admin, &expert, &uri, &vec![&env]);
 
     // Create update with URI that's too long (>64 chars)
     let long_str = "a".repeat(65);
     let long_uri = String::from_str(&env, long_str.as_str());
 
-    let updates = vec![&env, (expert.clone(), long_uri, 1u32, 0u32)];
+    let updates = vec![&env, (expert.clone(), long_uri, 1u32, vec![&env])];
 
     // This should fail with UriTooLong error
-    let result = client.try_batch_update_profiles(&updates);
+    let result = client.try_batch_update_profiles(&admin, &updates);
     assert_eq!(result, Err(Ok(RegistryError::UriTooLong)));
 }
 
@@ -723,7 +734,7 @@ fn test_expert_pagination() {
     for _ in 0..15 {
         let expert = Address::generate(&env);
         let uri = String::from_str(&env, "ipfs://expert");
-        client.add_expert(&admin, &expert, &uri, &0u32);
+        client.add_expert(&admin, &expert, &uri, &vec![&env]);
         experts.push_back(expert);
     }
 
@@ -745,7 +756,10 @@ fn test_expert_pagination() {
 
     // Verify the last 5 experts match
     for i in 0..5 {
-        assert_eq!(page2.get(i as u32).unwrap(), experts.get((i + 10) as u32).unwrap());
+        assert_eq!(
+            page2.get(i as u32).unwrap(),
+            experts.get((i + 10) as u32).unwrap()
+        );
     }
 }
 
@@ -762,7 +776,7 @@ fn test_unban_expert() {
 
     env.mock_all_auths();
     let data_uri = String::from_str(&env, "ipfs://unban");
-    client.add_expert(&admin, &expert, &data_uri, &0u32);
+    client.add_expert(&admin, &expert, &data_uri, &vec![&env]);
 
     // Initial status: Verified
     assert_eq!(client.get_status(&expert), ExpertStatus::Verified);
@@ -773,7 +787,7 @@ fn test_unban_expert() {
     assert_eq!(client.get_status(&expert), ExpertStatus::Banned);
 
     // Unban the expert
-    client.unban_expert(&expert);
+    client.unban_expert(&admin, &expert);
     assert_eq!(client.get_status(&expert), ExpertStatus::Verified);
 
     // Data URI should be preserved
@@ -786,7 +800,7 @@ fn test_unban_expert() {
     assert_eq!(client.get_total_experts(), initial_total);
 
     // Test: Try to unban a non-banned expert (should fail with NotBanned)
-    let result = client.try_unban_expert(&expert);
+    let result = client.try_unban_expert(&admin, &expert);
     assert_eq!(result, Err(Ok(RegistryError::NotBanned)));
 }
 
@@ -809,14 +823,38 @@ fn test_moderator_can_verify_expert() {
 
     // Moderator verifies an expert (should succeed)
     let uri = String::from_str(&env, "ipfs://mod-verify");
-    let res = client.try_add_expert(&moderator, &expert, &uri, &0u32);
+    let res = client.try_add_expert(&moderator, &expert, &uri, &vec![&env]);
     assert!(res.is_ok());
 
     assert_eq!(client.get_status(&expert), ExpertStatus::Verified);
 }
 
 #[test]
-fn test_moderator_can_ban_expert() {
+fn test_moderator_cannot_reverify_banned_expert() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let moderator = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://mod-reverify");
+
+    client.init(&admin);
+    client.add_moderator(&moderator);
+    client.add_expert(&admin, &expert, &uri, &vec![&env]);
+    client.ban_expert(&admin, &expert);
+
+    // Only the admin's unban_expert lifts a ban
+    let res = client.try_add_expert(&moderator, &expert, &uri, &vec![&env]);
+    assert_eq!(res, Err(Ok(RegistryError::AlreadyBanned)));
+    assert_eq!(client.get_status(&expert), ExpertStatus::Banned);
+}
+
+#[test]
+fn test_moderator_cannot_ban_expert() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -832,13 +870,13 @@ fn test_moderator_can_ban_expert() {
 
     // Verify expert first (by admin)
     let uri = String::from_str(&env, "ipfs://mod-ban");
-    client.add_expert(&admin, &expert, &uri, &0u32);
+    client.add_expert(&admin, &expert, &uri, &vec![&env]);
 
-    // Moderator bans the expert (should succeed)
+    // Permanent bans are admin-only; moderators suspend instead
     let res = client.try_ban_expert(&moderator, &expert);
-    assert!(res.is_ok());
+    assert_eq!(res, Err(Ok(RegistryError::Unauthorized)));
 
-    assert_eq!(client.get_status(&expert), ExpertStatus::Banned);
+    assert_eq!(client.get_status(&expert), ExpertStatus::Verified);
 }
 
 #[test]
@@ -857,7 +895,7 @@ fn test_non_moderator_cannot_verify_expert() {
 
     // Random address (not admin, not moderator) tries to verify — should fail with Unauthorized
     let uri = String::from_str(&env, "ipfs://unauth");
-    let res = client.try_add_expert(&random, &expert, &uri, &0u32);
+    let res = client.try_add_expert(&random, &expert, &uri, &vec![&env]);
     assert_eq!(res, Err(Ok(RegistryError::Unauthorized)));
 }
 
@@ -877,21 +915,256 @@ fn test_remove_moderator() {
     client.add_moderator(&moderator);
 
     // Verify moderator is set
-    env.as_contract(&contract_id, || {
-        assert!(storage::is_moderator(&env, &moderator));
-    });
+    assert!(client.has_role(&Role::Moderator, &moderator));
 
     // Remove the moderator
     client.remove_moderator(&moderator);
+    assert!(!client.has_role(&Role::Moderator, &moderator));
 
     // After removal, moderator can no longer verify experts
     let uri = String::from_str(&env, "ipfs://removed-mod");
-    let res = client.try_add_expert(&moderator, &expert, &uri, &0u32);
+    let res = client.try_add_expert(&moderator, &expert, &uri, &vec![&env]);
+    assert_eq!(res, Err(Ok(RegistryError::Unauthorized)));
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let moderator = Address::generate(&env);
+
+    client.init(&admin);
+    client.propose_admin(&new_admin);
+
+    let events = env.events().all();
+    let topic: Symbol = events
+        .last()
+        .unwrap()
+        .1
+        .get(0)
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(topic, Symbol::new(&env, "admin_proposed"));
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    // The current admin stays in charge until the nominee accepts
+    assert!(client.has_role(&Role::Admin, &admin));
+    client.accept_admin();
+
+    let events = env.events().all();
+    let topic: Symbol = events
+        .last()
+        .unwrap()
+        .1
+        .get(0)
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(topic, Symbol::new(&env, "admin_transferred"));
+    assert_eq!(client.get_pending_admin(), None);
+
+    assert!(!client.has_role(&Role::Admin, &admin));
+    assert!(client.has_role(&Role::Admin, &new_admin));
+    assert!(client.has_role(&Role::Upgrader, &new_admin));
+    client.add_moderator(&moderator);
+    assert!(client.has_role(&Role::Moderator, &moderator));
+}
+
+#[test]
+fn test_cancel_admin_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    client.init(&admin);
+    client.propose_admin(&new_admin);
+    client.cancel_admin_transfer();
+
+    let events = env.events().all();
+    let topic: Symbol = events
+        .last()
+        .unwrap()
+        .1
+        .get(0)
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(topic, Symbol::new(&env, "admin_transfer_cancelled"));
+
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(RegistryError::NoPendingAdmin))
+    );
+    assert_eq!(
+        client.try_cancel_admin_transfer(),
+        Err(Ok(RegistryError::NoPendingAdmin))
+    );
+    assert!(client.has_role(&Role::Admin, &admin));
+}
+
+#[test]
+#[should_panic]
+fn test_accept_admin_requires_pending_admin_auth() {
+    let env = Env::default();
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init(&admin);
+    client.propose_admin(&new_admin);
+
+    env.mock_auths(&[]);
+    client.accept_admin();
+}
+
+#[test]
+fn test_legacy_moderators_keep_their_rights() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let legacy_moderator = Address::generate(&env);
+    let removed_moderator = Address::generate(&env);
+    let expert = Address::generate(&env);
+
+    client.init(&admin);
+
+    // Moderators stored before the move to role storage
+    env.as_contract(&contract_id, || {
+        for moderator in [&legacy_moderator, &removed_moderator] {
+            env.storage()
+                .instance()
+                .set(&storage::DataKey::Moderator(moderator.clone()), &true);
+        }
+    });
+    assert!(client.has_role(&Role::Moderator, &legacy_moderator));
+
+    // Acting as a moderator moves the flag into role storage
+    let uri = String::from_str(&env, "ipfs://legacy-mod");
+    client.add_expert(&legacy_moderator, &expert, &uri, &vec![&env]);
+    assert_eq!(client.get_status(&expert), ExpertStatus::Verified);
+    env.as_contract(&contract_id, || {
+        assert!(!storage::is_legacy_moderator(&env, &legacy_moderator));
+    });
+    assert!(client.has_role(&Role::Moderator, &legacy_moderator));
+
+    // Removing a moderator also clears the legacy flag
+    client.remove_moderator(&removed_moderator);
+    assert!(!client.has_role(&Role::Moderator, &removed_moderator));
+    let other = Address::generate(&env);
+    let res = client.try_add_expert(&removed_moderator, &other, &uri, &vec![&env]);
+    assert_eq!(res, Err(Ok(RegistryError::Unauthorized)));
+}
+
+#[test]
+fn test_role_admin_can_grant_moderator_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let co_admin = Address::generate(&env);
+    let moderator = Address::generate(&env);
+    let expert = Address::generate(&env);
+
+    client.init(&admin);
+    assert!(client.has_role(&Role::Admin, &admin));
+
+    // A second Admin role holder can appoint moderators
+    client.grant_role(&admin, &Role::Admin, &co_admin);
+    client.grant_role(&co_admin, &Role::Moderator, &moderator);
+
+    let uri = String::from_str(&env, "ipfs://role-mod");
+    client.add_expert(&moderator, &expert, &uri, &vec![&env]);
+    assert_eq!(client.get_status(&expert), ExpertStatus::Verified);
+
+    // Moderators cannot manage roles, and the primary admin cannot be stripped
+    let res = client.try_grant_role(&moderator, &Role::Moderator, &expert);
     assert_eq!(res, Err(Ok(RegistryError::Unauthorized)));
+    let res = client.try_revoke_role(&co_admin, &Role::Admin, &admin);
+    assert_eq!(res, Err(Ok(RegistryError::Unauthorized)));
+}
+
+#[test]
+fn test_schedule_upgrade_is_timelocked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+    env.ledger().set_timestamp(1_000);
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+    client.schedule_upgrade(&admin, &hash);
+
+    let pending = client.get_pending_upgrade().unwrap();
+    assert_eq!(pending.wasm_hash, hash);
+    assert_eq!(pending.executable_at, 1_000 + client.get_upgrade_delay());
+
+    env.ledger().set_timestamp(pending.executable_at - 1);
+    assert_eq!(
+        client.try_execute_upgrade(&admin),
+        Err(Ok(RegistryError::UpgradeNotReady))
+    );
+
+    client.cancel_upgrade(&admin);
+    assert_eq!(client.get_pending_upgrade(), None);
+    assert_eq!(
+        client.try_execute_upgrade(&admin),
+        Err(Ok(RegistryError::NoPendingUpgrade))
+    );
+}
+
+#[test]
+fn test_set_upgrade_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+    let upgrader = Address::generate(&env);
+    client.grant_role(&admin, &Role::Upgrader, &upgrader);
+
+    client.set_upgrade_delay(&admin, &(7 * 86_400));
+    assert_eq!(client.get_upgrade_delay(), 7 * 86_400);
+    assert_eq!(
+        client.try_set_upgrade_delay(&admin, &60),
+        Err(Ok(RegistryError::InvalidUpgradeDelay))
+    );
+
+    // Upgraders cannot shorten their own notice period
+    assert_eq!(
+        client.try_set_upgrade_delay(&upgrader, &86_400),
+        Err(Ok(RegistryError::Unauthorized))
+    );
 }
 
 #[test]
-fn test_category_id_persisted_and_updated() {
+fn test_category_ids_persisted_and_updated() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -902,41 +1175,757 @@ fn test_category_id_persisted_and_updated() {
     let expert = Address::generate(&env);
 
     client.init(&admin);
+    client.add_category(&admin, &5u32, &String::from_str(&env, "Design"), &0u32);
+    client.add_category(
+        &admin,
+        &10u32,
+        &String::from_str(&env, "Engineering"),
+        &0u32,
+    );
 
-    // Add expert with category_id = 5
+    // Add expert with category 5
     let uri = String::from_str(&env, "ipfs://cat");
-    client.add_expert(&admin, &expert, &uri, &5u32);
+    client.add_expert(&admin, &expert, &uri, &vec![&env, 5u32]);
 
     env.as_contract(&contract_id, || {
         let rec = storage::get_expert_record(&env, &expert);
-        assert_eq!(rec.category_id, 5);
+        assert_eq!(rec.category_ids, vec![&env, 5u32]);
     });
 
-    // Update profile with new category_id = 10
+    // Update profile with categories 5 and 10
     let uri2 = String::from_str(&env, "ipfs://cat2");
-    client.update_profile(&expert, &uri2, &10u32);
+    client.update_profile(&expert, &uri2, &vec![&env, 5u32, 10u32]);
 
     env.as_contract(&contract_id, || {
         let rec = storage::get_expert_record(&env, &expert);
-        assert_eq!(rec.category_id, 10);
+        assert_eq!(rec.category_ids, vec![&env, 5u32, 10u32]);
         assert_eq!(rec.data_uri, uri2);
     });
 
-    // Ban and verify category_id is preserved
+    // Ban and verify category_ids are preserved
     client.ban_expert(&admin, &expert);
 
     env.as_contract(&contract_id, || {
         let rec = storage::get_expert_record(&env, &expert);
-        assert_eq!(rec.category_id, 10);
+        assert_eq!(rec.category_ids, vec![&env, 5u32, 10u32]);
         assert_eq!(rec.status, ExpertStatus::Banned);
     });
 
-    // Unban and verify category_id is preserved
-    client.unban_expert(&expert);
+    // Unban and verify category_ids are preserved
+    client.unban_expert(&admin, &expert);
 
     env.as_contract(&contract_id, || {
         let rec = storage::get_expert_record(&env, &expert);
-        assert_eq!(rec.category_id, 10);
+        assert_eq!(rec.category_ids, vec![&env, 5u32, 10u32]);
         assert_eq!(rec.status, ExpertStatus::Verified);
     });
 }
+
+// ==================== Soulbound Credential Tests ====================
+
+#[test]
+fn test_verify_expert_mints_credential() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://credential");
+
+    client.init(&admin);
+    assert_eq!(client.balance(&expert), 0);
+    assert_eq!(
+        client.try_token_uri(&expert),
+        Err(Ok(RegistryError::ExpertNotFound))
+    );
+
+    client.add_expert(&admin, &expert, &uri, &vec![&env]);
+
+    let minted = env.events().all().iter().any(|event| {
+        let topic: Symbol = event.1.get(0).unwrap().try_into_val(&env).unwrap();
+        topic == Symbol::new(&env, "credential_minted")
+    });
+    assert!(minted);
+
+    assert_eq!(client.balance(&expert), 1);
+    assert_eq!(client.token_uri(&expert), uri);
+    assert_eq!(client.decimals(), 0);
+    assert_eq!(
+        client.name(),
+        String::from_str(&env, "SkillSphere Expert Credential")
+    );
+    assert_eq!(client.symbol(), String::from_str(&env, "SSEXPERT"));
+}
+
+#[test]
+fn test_ban_freezes_and_unban_restores_credential() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+
+    client.init(&admin);
+    client.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://x"),
+        &vec![&env],
+    );
+    let token_id = env.as_contract(&contract_id, || {
+        storage::get_credential(&env, &expert).unwrap().token_id
+    });
+
+    client.ban_expert(&admin, &expert);
+    assert_eq!(client.balance(&expert), 0);
+
+    // Unbanning thaws the same credential rather than minting a new one
+    client.unban_expert(&admin, &expert);
+    assert_eq!(client.balance(&expert), 1);
+    env.as_contract(&contract_id, || {
+        let credential = storage::get_credential(&env, &expert).unwrap();
+        assert_eq!(credential.token_id, token_id);
+        assert!(!credential.frozen);
+    });
+}
+
+#[test]
+fn test_batch_operations_sync_credentials() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert1 = Address::generate(&env);
+    let expert2 = Address::generate(&env);
+
+    client.init(&admin);
+    client.batch_add_experts(&admin, &vec![&env, expert1.clone(), expert2.clone()]);
+    assert_eq!(client.balance(&expert1), 1);
+    assert_eq!(client.balance(&expert2), 1);
+
+    client.batch_ban_experts(&admin, &vec![&env, expert1.clone()]);
+    assert_eq!(client.balance(&expert1), 0);
+    assert_eq!(client.balance(&expert2), 1);
+}
+
+#[test]
+fn test_credential_is_non_transferable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.init(&admin);
+    client.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://x"),
+        &vec![&env],
+    );
+
+    assert_eq!(
+        client.try_transfer(&expert, &other, &1),
+        Err(Ok(RegistryError::NonTransferable))
+    );
+    assert_eq!(
+        client.try_transfer_from(&other, &expert, &other, &1),
+        Err(Ok(RegistryError::NonTransferable))
+    );
+    assert_eq!(
+        client.try_approve(&expert, &other, &1, &1_000),
+        Err(Ok(RegistryError::NonTransferable))
+    );
+    assert_eq!(client.allowance(&expert, &other), 0);
+    assert_eq!(client.balance(&expert), 1);
+    assert_eq!(client.balance(&other), 0);
+}
+
+// ==================== Category Registry Tests ====================
+
+#[test]
+fn test_add_category_and_list() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.init(&admin);
+
+    client.add_category(&admin, &1u32, &String::from_str(&env, "Engineering"), &0u32);
+    client.add_category(&admin, &2u32, &String::from_str(&env, "Rust"), &1u32);
+    client.set_category_active(&admin, &1u32, &false);
+
+    let categories = client.get_categories();
+    assert_eq!(categories.len(), 2);
+    let parent = categories.get(0).unwrap();
+    assert_eq!(parent.name, String::from_str(&env, "Engineering"));
+    assert!(!parent.active);
+    let child = categories.get(1).unwrap();
+    assert_eq!(child.parent_id, 1);
+    assert!(child.active);
+}
+
+#[test]
+fn test_add_category_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.init(&admin);
+    let name = String::from_str(&env, "Law");
+
+    client.add_category(&admin, &1u32, &name, &0u32);
+    assert_eq!(
+        client.try_add_category(&admin, &1u32, &name, &0u32),
+        Err(Ok(RegistryError::CategoryExists))
+    );
+    assert_eq!(
+        client.try_add_category(&admin, &0u32, &name, &0u32),
+        Err(Ok(RegistryError::InvalidCategory))
+    );
+    assert_eq!(
+        client.try_add_category(&admin, &2u32, &name, &9u32),
+        Err(Ok(RegistryError::CategoryNotFound))
+    );
+    assert_eq!(
+        client.try_set_category_active(&admin, &9u32, &true),
+        Err(Ok(RegistryError::CategoryNotFound))
+    );
+}
+
+#[test]
+fn test_expert_categories_checked_against_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://x");
+
+    client.init(&admin);
+    for id in 1u32..=6 {
+        client.add_category(&admin, &id, &String::from_str(&env, "Category"), &0u32);
+    }
+    client.set_category_active(&admin, &6u32, &false);
+
+    assert_eq!(
+        client.try_add_expert(&admin, &expert, &uri, &vec![&env, 7u32]),
+        Err(Ok(RegistryError::CategoryNotFound))
+    );
+    assert_eq!(
+        client.try_add_expert(&admin, &expert, &uri, &vec![&env, 6u32]),
+        Err(Ok(RegistryError::CategoryInactive))
+    );
+    assert_eq!(
+        client.try_add_expert(&admin, &expert, &uri, &vec![&env, 1u32, 1u32]),
+        Err(Ok(RegistryError::InvalidCategory))
+    );
+    assert_eq!(
+        client.try_add_expert(&admin, &expert, &uri, &vec![&env, 1u32, 2, 3, 4, 5, 6]),
+        Err(Ok(RegistryError::TooManyCategories))
+    );
+
+    client.add_expert(&admin, &expert, &uri, &vec![&env, 1u32, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_get_experts_by_category() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert1 = Address::generate(&env);
+    let expert2 = Address::generate(&env);
+    let expert3 = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://x");
+
+    client.init(&admin);
+    client.add_category(&admin, &1u32, &String::from_str(&env, "Design"), &0u32);
+    client.add_category(&admin, &2u32, &String::from_str(&env, "Law"), &0u32);
+
+    client.add_expert(&admin, &expert1, &uri, &vec![&env, 1u32]);
+    client.add_expert(&admin, &expert2, &uri, &vec![&env, 1u32, 2u32]);
+    client.add_expert(&admin, &expert3, &uri, &vec![&env, 1u32]);
+
+    assert_eq!(
+        client.get_experts_by_category(&1u32, &0u64, &10u64),
+        vec![&env, expert1.clone(), expert2.clone(), expert3.clone()]
+    );
+    assert_eq!(
+        client.get_experts_by_category(&1u32, &1u64, &1u64),
+        vec![&env, expert2.clone()]
+    );
+    assert_eq!(
+        client.get_experts_by_category(&2u32, &0u64, &10u64),
+        vec![&env, expert2.clone()]
+    );
+
+    // Dropping a category removes the expert from that index only
+    client.update_profile(&expert1, &uri, &vec![&env, 2u32]);
+    assert_eq!(
+        client.get_experts_by_category(&1u32, &0u64, &10u64),
+        vec![&env, expert3.clone(), expert2.clone()]
+    );
+    assert_eq!(
+        client.get_experts_by_category(&2u32, &0u64, &10u64),
+        vec![&env, expert2, expert1]
+    );
+}
+
+// ==================== Verification Application Tests ====================
+
+#[test]
+fn test_application_approved_verifies_expert() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let moderator = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://application");
+    let evidence = BytesN::from_array(&env, &[1u8; 32]);
+
+    client.init(&admin);
+    client.add_moderator(&moderator);
+    client.add_category(&admin, &1u32, &String::from_str(&env, "Design"), &0u32);
+
+    let id = client.apply_for_verification(&expert, &uri, &vec![&env, 1u32], &evidence);
+    assert_eq!(client.get_application_count(), 1);
+    assert!(!client.is_verified(&expert));
+
+    let application = client.get_application(&id).unwrap();
+    assert_eq!(application.status, ApplicationStatus::Pending);
+    assert_eq!(application.evidence_hash, evidence);
+
+    client.approve_application(&moderator, &id);
+
+    let approved = env.events().all().iter().any(|event| {
+        let topic: Symbol = event.1.get(0).unwrap().try_into_val(&env).unwrap();
+        topic == Symbol::new(&env, "application_approved")
+    });
+    assert!(approved);
+
+    assert!(client.is_verified(&expert));
+    assert_eq!(client.balance(&expert), 1);
+    let application = client.get_latest_application(&expert).unwrap();
+    assert_eq!(application.status, ApplicationStatus::Approved);
+    assert_eq!(application.reviewed_by, Some(moderator));
+    env.as_contract(&contract_id, || {
+        let rec = storage::get_expert_record(&env, &expert);
+        assert_eq!(rec.data_uri, uri);
+        assert_eq!(rec.category_ids, vec![&env, 1u32]);
+    });
+}
+
+#[test]
+fn test_application_rejected_with_reason() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://application");
+    let evidence = BytesN::from_array(&env, &[1u8; 32]);
+
+    client.init(&admin);
+
+    let id = client.apply_for_verification(&expert, &uri, &vec![&env], &evidence);
+    client.reject_application(&admin, &id, &42u32);
+
+    let application = client.get_application(&id).unwrap();
+    assert_eq!(application.status, ApplicationStatus::Rejected);
+    assert_eq!(application.reason_code, 42);
+    assert!(!client.is_verified(&expert));
+
+    // Reviewed applications cannot be reviewed again
+    assert_eq!(
+        client.try_approve_application(&admin, &id),
+        Err(Ok(RegistryError::ApplicationNotPending))
+    );
+
+    // The expert may re-apply after a rejection
+    let second = client.apply_for_verification(&expert, &uri, &vec![&env], &evidence);
+    assert_eq!(second, 1);
+}
+
+#[test]
+fn test_apply_for_verification_guards() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let verified = Address::generate(&env);
+    let banned = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://application");
+    let evidence = BytesN::from_array(&env, &[1u8; 32]);
+
+    client.init(&admin);
+    client.add_expert(&admin, &verified, &uri, &vec![&env]);
+    client.ban_expert(&admin, &banned);
+
+    client.apply_for_verification(&expert, &uri, &vec![&env], &evidence);
+    assert_eq!(
+        client.try_apply_for_verification(&expert, &uri, &vec![&env], &evidence),
+        Err(Ok(RegistryError::ApplicationPending))
+    );
+    assert_eq!(
+        client.try_apply_for_verification(&verified, &uri, &vec![&env], &evidence),
+        Err(Ok(RegistryError::AlreadyVerified))
+    );
+    assert_eq!(
+        client.try_apply_for_verification(&banned, &uri, &vec![&env], &evidence),
+        Err(Ok(RegistryError::AlreadyBanned))
+    );
+    let newcomer = Address::generate(&env);
+    assert_eq!(
+        client.try_apply_for_verification(&newcomer, &uri, &vec![&env, 9u32], &evidence),
+        Err(Ok(RegistryError::CategoryNotFound))
+    );
+}
+
+#[test]
+fn test_non_moderator_cannot_review_application() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://application");
+    let evidence = BytesN::from_array(&env, &[1u8; 32]);
+
+    client.init(&admin);
+    let id = client.apply_for_verification(&expert, &uri, &vec![&env], &evidence);
+
+    assert_eq!(
+        client.try_approve_application(&stranger, &id),
+        Err(Ok(RegistryError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_reject_application(&stranger, &id, &1u32),
+        Err(Ok(RegistryError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_approve_application(&admin, &7u64),
+        Err(Ok(RegistryError::ApplicationNotFound))
+    );
+}
+
+#[test]
+fn test_moderator_cannot_approve_banned_experts_application() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let moderator = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://application");
+    let evidence = BytesN::from_array(&env, &[1u8; 32]);
+
+    client.init(&admin);
+    client.add_moderator(&moderator);
+
+    // The ban lands while the application is still pending
+    let id = client.apply_for_verification(&expert, &uri, &vec![&env], &evidence);
+    client.ban_expert(&admin, &expert);
+
+    assert_eq!(
+        client.try_approve_application(&moderator, &id),
+        Err(Ok(RegistryError::AlreadyBanned))
+    );
+    assert_eq!(client.get_status(&expert), ExpertStatus::Banned);
+    assert_eq!(
+        client.get_application(&id).unwrap().status,
+        ApplicationStatus::Pending
+    );
+}
+
+// ==================== Suspension Tests ====================
+
+#[test]
+fn test_moderator_suspension_lifts_automatically() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let moderator = Address::generate(&env);
+    let expert = Address::generate(&env);
+
+    client.init(&admin);
+    client.add_moderator(&moderator);
+    client.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://x"),
+        &vec![&env],
+    );
+
+    env.ledger().set_timestamp(1_000);
+    client.suspend_expert(&moderator, &expert, &3_600u64, &7u32);
+
+    assert_eq!(client.get_status(&expert), ExpertStatus::Suspended);
+    assert!(!client.is_verified(&expert));
+    assert_eq!(client.balance(&expert), 0);
+    let suspension = client.get_suspension(&expert).unwrap();
+    assert_eq!(suspension.until, 4_600);
+    assert_eq!(suspension.reason_code, 7);
+    assert_eq!(suspension.suspended_by, moderator);
+
+    // Still suspended just before the end time
+    env.ledger().set_timestamp(4_599);
+    assert!(!client.is_verified(&expert));
+
+    env.ledger().set_timestamp(4_600);
+    assert_eq!(client.get_status(&expert), ExpertStatus::Verified);
+    assert!(client.is_verified(&expert));
+    assert_eq!(client.balance(&expert), 1);
+}
+
+#[test]
+fn test_lift_suspension_early() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+
+    client.init(&admin);
+    client.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://x"),
+        &vec![&env],
+    );
+    client.suspend_expert(&admin, &expert, &3_600u64, &1u32);
+
+    client.lift_suspension(&admin, &expert);
+    assert!(client.is_verified(&expert));
+    assert_eq!(client.get_suspension(&expert), None);
+    assert_eq!(
+        client.try_lift_suspension(&admin, &expert),
+        Err(Ok(RegistryError::NotSuspended))
+    );
+}
+
+#[test]
+fn test_suspend_expert_guards() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let unverified = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://x");
+
+    client.init(&admin);
+    client.add_expert(&admin, &expert, &uri, &vec![&env]);
+
+    assert_eq!(
+        client.try_suspend_expert(&stranger, &expert, &3_600u64, &1u32),
+        Err(Ok(RegistryError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_suspend_expert(&admin, &expert, &0u64, &1u32),
+        Err(Ok(RegistryError::InvalidSuspension))
+    );
+    assert_eq!(
+        client.try_suspend_expert(&admin, &expert, &(91 * 86_400u64), &1u32),
+        Err(Ok(RegistryError::InvalidSuspension))
+    );
+    assert_eq!(
+        client.try_suspend_expert(&admin, &unverified, &3_600u64, &1u32),
+        Err(Ok(RegistryError::NotVerified))
+    );
+
+    // A suspended expert cannot be re-verified around the suspension
+    client.suspend_expert(&admin, &expert, &3_600u64, &1u32);
+    assert_eq!(
+        client.try_add_expert(&admin, &expert, &uri, &vec![&env]),
+        Err(Ok(RegistryError::ExpertSuspended))
+    );
+
+    // The admin can still escalate to a permanent ban
+    client.ban_expert(&admin, &expert);
+    assert_eq!(client.get_status(&expert), ExpertStatus::Banned);
+}
+
+#[test]
+fn test_batch_update_profiles_respects_suspensions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://x");
+    let new_uri = String::from_str(&env, "ipfs://updated");
+
+    client.init(&admin);
+    client.add_expert(&admin, &expert, &uri, &vec![&env]);
+    client.suspend_expert(&admin, &expert, &3_600u64, &1u32);
+
+    // An active suspension cannot be overwritten, and suspensions need a duration
+    let updates = vec![&env, (expert.clone(), new_uri.clone(), 1u32, vec![&env])];
+    assert_eq!(
+        client.try_batch_update_profiles(&admin, &updates),
+        Err(Ok(RegistryError::ExpertSuspended))
+    );
+    let suspend = vec![&env, (expert.clone(), new_uri.clone(), 3u32, vec![&env])];
+    assert_eq!(
+        client.try_batch_update_profiles(&admin, &suspend),
+        Err(Ok(RegistryError::InvalidSuspension))
+    );
+
+    // Once it lapses, the rewrite also clears the old suspension record
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3_600);
+    client.batch_update_profiles(&admin, &updates);
+    assert_eq!(client.get_status(&expert), ExpertStatus::Verified);
+    assert_eq!(client.get_suspension(&expert), None);
+}
+
+#[test]
+fn test_secondary_admin_can_ban_expert() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let co_admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+
+    client.init(&admin);
+    client.grant_role(&admin, &Role::Admin, &co_admin);
+    client.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://x"),
+        &vec![&env],
+    );
+
+    client.ban_expert(&co_admin, &expert);
+    assert_eq!(client.get_status(&expert), ExpertStatus::Banned);
+}
+
+#[test]
+fn test_migrate_roles_from_pre_rbac_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    // A contract deployed before role storage only recorded the admin address
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&crate::storage::DataKey::Admin, &admin);
+    });
+    assert_eq!(
+        client.try_ban_expert(&admin, &expert),
+        Err(Ok(RegistryError::Unauthorized))
+    );
+
+    client.migrate_roles();
+    for role in access_control::ADMIN_ROLES {
+        assert!(client.has_role(&role, &admin));
+    }
+    client.ban_expert(&admin, &expert);
+    assert_eq!(
+        client.try_migrate_roles(),
+        Err(Ok(RegistryError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_admin_batch_operations_require_admin_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(IdentityRegistryContract, ());
+    let client = IdentityRegistryContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let moderator = Address::generate(&env);
+    let expert = Address::generate(&env);
+    client.init(&admin);
+    client.add_moderator(&moderator);
+    let name = String::from_str(&env, "Design");
+
+    let unauthorized = Err(Ok(RegistryError::Unauthorized));
+    let experts = vec![&env, expert.clone()];
+    assert_eq!(
+        client.try_batch_add_experts(&moderator, &experts),
+        unauthorized
+    );
+    assert_eq!(
+        client.try_batch_ban_experts(&moderator, &experts),
+        unauthorized
+    );
+    assert_eq!(client.try_unban_expert(&moderator, &expert), unauthorized);
+    assert_eq!(
+        client.try_add_category(&moderator, &1u32, &name, &0u32),
+        unauthorized
+    );
+    assert_eq!(
+        client.try_set_category_active(&moderator, &1u32, &false),
+        unauthorized
+    );
+    let updates = vec![&env, (expert, name, 1u32, vec![&env])];
+    assert_eq!(
+        client.try_batch_update_profiles(&moderator, &updates),
+        unauthorized
+    );
+}
@@ -1,5 +1,5 @@
 use soroban_sdk::contracttype;
-use soroban_sdk::String;
+use soroban_sdk::{Address, BytesN, String, Vec};
 
 // 1. Expert Status Enum
 #[contracttype]
@@ -9,6 +9,7 @@ pub enum ExpertStatus {
     Unverified = 0,
     Verified = 1,
     Banned = 2,
+    Suspended = 3, // Temporary; see `Suspension` for when it lifts
 }
 
 // 2. Expert Record Struct
@@ -18,5 +19,59 @@ pub struct ExpertRecord {
     pub status: ExpertStatus,
     pub updated_at: u64, // Ledger timestamp of the last change
     pub data_uri: String,
-    pub category_id: u32,
+    pub category_ids: Vec<u32>, // Registered categories the expert covers (empty = uncategorized)
+}
+
+// 3. Soulbound Credential Struct
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Credential {
+    pub token_id: u64,
+    pub owner: Address,
+    pub issued_at: u64, // Ledger timestamp of the first verification
+    pub frozen: bool,   // Set while the expert is not verified (e.g. banned)
+}
+
+// 4. Category Struct
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Category {
+    pub id: u32,
+    pub name: String,
+    pub parent_id: u32, // 0 for top-level categories
+    pub active: bool,   // Inactive categories cannot be assigned to experts
+}
+
+// 5. Verification Application Status Enum
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ApplicationStatus {
+    Pending = 0,
+    Approved = 1,
+    Rejected = 2,
+}
+
+// 6. Verification Application Struct
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Application {
+    pub id: u64,
+    pub expert: Address,
+    pub data_uri: String,
+    pub category_ids: Vec<u32>,
+    pub evidence_hash: BytesN<32>, // Hash of the off-chain supporting documents
+    pub status: ApplicationStatus,
+    pub reason_code: u32, // Rejection reason, 0 unless rejected
+    pub submitted_at: u64,
+    pub reviewed_by: Option<Address>,
+}
+
+// 7. Suspension Struct
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Suspension {
+    pub until: u64, // Ledger timestamp at which the expert is Verified again
+    pub reason_code: u32,
+    pub suspended_by: Address,
 }
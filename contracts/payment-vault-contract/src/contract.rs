@@ -1,8 +1,12 @@
 use crate::error::VaultError;
 use crate::events;
 use crate::storage;
-use crate::types::{BookingRecord, BookingStatus};
-use soroban_sdk::{token, Address, Env, Symbol};
+use crate::types::{
+    BookingRecord, BookingStatus, CalendarReservation, DisputeAction, DisputeProposal,
+    DurationReport, SessionKey, SessionOutcome, TokenLiabilities,
+};
+use access_control::Role;
+use soroban_sdk::{token, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Symbol, Vec};
 
 pub fn initialize_vault(
     env: &Env,
@@ -18,38 +22,118 @@ pub fn initialize_vault(
 
     // 2. Save State
     storage::set_admin(env, admin);
-    storage::set_token(env, token);
+    storage::set_token_allowed(env, token, true);
     storage::set_oracle(env, oracle);
     storage::set_registry_address(env, registry);
+    access_control::init_admin(env, admin);
+    access_control::grant_role(env, Role::Oracle, oracle, admin);
 
     Ok(())
 }
 
-pub fn pause(env: &Env) -> Result<(), VaultError> {
-    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
-    admin.require_auth();
+pub fn pause(env: &Env, caller: &Address) -> Result<(), VaultError> {
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Pauser, caller)?;
     storage::set_paused(env, true);
     events::contract_paused(env, true);
     Ok(())
 }
 
-pub fn unpause(env: &Env) -> Result<(), VaultError> {
-    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
-    admin.require_auth();
+pub fn unpause(env: &Env, caller: &Address) -> Result<(), VaultError> {
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Pauser, caller)?;
     storage::set_paused(env, false);
     events::contract_paused(env, false);
     Ok(())
 }
 
-pub fn set_my_rate(env: &Env, expert: &Address, rate_per_second: i128) -> Result<(), VaultError> {
+/// Grant `role` to `account` (Admin role only).
+/// The Oracle and Treasurer roles follow `set_oracle` and `set_fee_config` instead.
+pub fn grant_role(
+    env: &Env,
+    caller: &Address,
+    role: Role,
+    account: &Address,
+) -> Result<(), VaultError> {
+    access_control::require_role(env, Role::Admin, caller)?;
+    if matches!(role, Role::Oracle | Role::Treasurer) {
+        return Err(VaultError::NotAuthorized);
+    }
+    access_control::grant_role(env, role, account, caller);
+    Ok(())
+}
+
+/// Revoke `role` from `account` (Admin role only).
+/// The primary admin keeps the Admin role until admin rights are transferred.
+pub fn revoke_role(
+    env: &Env,
+    caller: &Address,
+    role: Role,
+    account: &Address,
+) -> Result<(), VaultError> {
+    access_control::require_role(env, Role::Admin, caller)?;
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    if matches!(role, Role::Oracle | Role::Treasurer) || (role == Role::Admin && *account == admin)
+    {
+        return Err(VaultError::NotAuthorized);
+    }
+    access_control::revoke_role(env, role, account, caller);
+    Ok(())
+}
+
+/// Hand the stored admin its roles, and the stored oracle the Oracle role, on a contract
+/// upgraded from before role storage (Admin-only).
+pub fn migrate_roles(env: &Env) -> Result<(), VaultError> {
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    admin.require_auth();
+    if !access_control::migrate_admin(env, &admin) {
+        return Err(VaultError::AlreadyInitialized);
+    }
+    access_control::grant_role(env, Role::Oracle, &storage::get_oracle(env), &admin);
+    Ok(())
+}
+
+pub fn add_payment_token(env: &Env, caller: &Address, token: &Address) -> Result<(), VaultError> {
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
+    storage::set_token_allowed(env, token, true);
+    events::payment_token_updated(env, token, true);
+    Ok(())
+}
+
+pub fn remove_payment_token(
+    env: &Env,
+    caller: &Address,
+    token: &Address,
+) -> Result<(), VaultError> {
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
+    if !storage::is_token_allowed(env, token) {
+        return Err(VaultError::TokenNotAllowed);
+    }
+    storage::set_token_allowed(env, token, false);
+    events::payment_token_updated(env, token, false);
+    Ok(())
+}
+
+pub fn set_my_rate(
+    env: &Env,
+    expert: &Address,
+    token: &Address,
+    rate_per_second: i128,
+) -> Result<(), VaultError> {
     expert.require_auth();
 
+    if !storage::is_token_allowed(env, token) {
+        return Err(VaultError::TokenNotAllowed);
+    }
+
     if rate_per_second <= 0 {
         return Err(VaultError::InvalidAmount);
     }
 
-    storage::set_expert_rate(env, expert, rate_per_second);
-    events::expert_rate_updated(env, expert, rate_per_second);
+    storage::set_expert_rate(env, expert, token, rate_per_second);
+    events::expert_rate_updated(env, expert, token, rate_per_second);
 
     Ok(())
 }
@@ -58,6 +142,7 @@ pub fn book_session(
     env: &Env,
     user: &Address,
     expert: &Address,
+    token: &Address,
     max_duration: u64,
 ) -> Result<u64, VaultError> {
     if storage::is_paused(env) {
@@ -79,9 +164,14 @@ pub fn book_session(
         return Err(VaultError::ExpertNotVerified);
     }
 
-    // Fetch the expert's rate
+    // The payment token must be on the allowlist
+    if !storage::is_token_allowed(env, token) {
+        return Err(VaultError::TokenNotAllowed);
+    }
+
+    // Fetch the expert's rate for the chosen token
     let rate_per_second =
-        storage::get_expert_rate(env, expert).ok_or(VaultError::ExpertRateNotSet)?;
+        storage::get_expert_rate(env, expert, token).ok_or(VaultError::ExpertRateNotSet)?;
 
     // Validate rate
     if rate_per_second <= 0 {
@@ -100,12 +190,12 @@ pub fn book_session(
     }
 
     // Get the token contract
-    let token_address = storage::get_token(env);
-    let token_client = token::Client::new(env, &token_address);
+    let token_client = token::Client::new(env, token);
 
     // Transfer tokens from user to this contract
     let contract_address = env.current_contract_address();
     token_client.transfer(user, &contract_address, &total_deposit);
+    lock_escrow(env, token, total_deposit)?;
 
     // Generate booking ID and create booking
     let booking_id = storage::get_next_booking_id(env);
@@ -113,6 +203,7 @@ pub fn book_session(
         id: booking_id,
         user: user.clone(),
         expert: expert.clone(),
+        token: token.clone(),
         rate_per_second,
         max_duration,
         total_deposit,
@@ -122,6 +213,7 @@ pub fn book_session(
         dispute_user_refund: None,
         dispute_expert_pay: None,
         dispute_remainder_recovered: false,
+        withdrawn_amount: 0,
     };
 
     // Save booking
@@ -131,133 +223,994 @@ pub fn book_session(
     storage::add_booking_to_user_list(env, user, booking_id);
     storage::add_booking_to_expert_list(env, expert, booking_id);
 
-    // Emit event for booking creation
-    events::booking_created(env, booking_id, user, expert, total_deposit);
+    // Emit event for booking creation
+    events::booking_created(env, booking_id, user, expert, total_deposit);
+
+    Ok(booking_id)
+}
+
+pub fn top_up_session(
+    env: &Env,
+    caller: &Address,
+    booking_id: u64,
+    additional_duration: u64,
+) -> Result<(), VaultError> {
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+
+    // Require authorization from the caller (booking owner or its session key)
+    caller.require_auth();
+
+    // Get booking and verify it exists
+    let mut booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+
+    // Verify the caller is the booking owner or a live session key for it
+    let mut session_key = if booking.user == *caller {
+        None
+    } else {
+        Some(get_live_session_key(env, &booking, caller)?)
+    };
+
+    // Verify booking is in Pending status
+    if booking.status != BookingStatus::Pending {
+        return Err(VaultError::BookingNotPending);
+    }
+
+    // Calculate extra cost
+    let extra_cost = booking
+        .rate_per_second
+        .checked_mul(additional_duration as i128)
+        .ok_or(VaultError::Overflow)?;
+
+    if extra_cost <= 0 {
+        return Err(VaultError::InvalidAmount);
+    }
+
+    // Get the token contract
+    let token_client = token::Client::new(env, &booking.token);
+    let contract_address = env.current_contract_address();
+
+    // Transfer extra tokens from user to this contract.
+    // Session keys pull from the allowance the user granted when registering the key.
+    match session_key.as_mut() {
+        None => token_client.transfer(&booking.user, &contract_address, &extra_cost),
+        Some(key) => {
+            let spent = key
+                .spent
+                .checked_add(extra_cost)
+                .ok_or(VaultError::Overflow)?;
+            if spent > key.spend_limit {
+                return Err(VaultError::SessionKeyLimitExceeded);
+            }
+            // The approval behind the budget lapses after SESSION_KEY_ALLOWANCE_LEDGERS;
+            // the user has to renew it before the key can pull again
+            if token_client.allowance(&booking.user, &contract_address) < extra_cost {
+                return Err(VaultError::SessionKeyAllowanceExpired);
+            }
+            token_client.transfer_from(
+                &contract_address,
+                &booking.user,
+                &contract_address,
+                &extra_cost,
+            );
+            key.spent = spent;
+            storage::set_session_key(env, booking_id, key);
+            // The pull consumed part of the shared allowance, so the budget shrinks with it
+            let budget = storage::get_session_key_budget(env, &booking.user, &booking.token);
+            storage::set_session_key_budget(
+                env,
+                &booking.user,
+                &booking.token,
+                budget - extra_cost,
+            );
+        }
+    }
+
+    lock_escrow(env, &booking.token, extra_cost)?;
+
+    // Update booking
+    booking.total_deposit = booking
+        .total_deposit
+        .checked_add(extra_cost)
+        .ok_or(VaultError::Overflow)?;
+    booking.max_duration = booking
+        .max_duration
+        .checked_add(additional_duration)
+        .ok_or(VaultError::Overflow)?;
+
+    // Save booking
+    storage::save_booking(env, &booking);
+
+    // Emit event
+    events::session_topped_up(env, booking_id, additional_duration, extra_cost);
+
+    Ok(())
+}
+
+/// Number of ledgers the token allowance backing a session key stays valid (~30 days).
+const SESSION_KEY_ALLOWANCE_LEDGERS: u32 = 518_400;
+
+/// Delegate a time- and amount-limited session key to a booking (User-only).
+/// Token allowances are per (owner, spender), so every session key of a user in the same token
+/// shares one allowance: the vault is approved for the unspent limits of all of them combined.
+pub fn register_session_key(
+    env: &Env,
+    user: &Address,
+    booking_id: u64,
+    session_key: &Address,
+    spend_limit: i128,
+) -> Result<(), VaultError> {
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+
+    user.require_auth();
+
+    let booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+
+    if booking.user != *user {
+        return Err(VaultError::NotAuthorized);
+    }
+
+    if booking.status != BookingStatus::Pending {
+        return Err(VaultError::BookingNotPending);
+    }
+
+    if is_session_key_expired(env, &booking) {
+        return Err(VaultError::SessionKeyExpired);
+    }
+
+    if spend_limit <= 0 {
+        return Err(VaultError::InvalidAmount);
+    }
+
+    // A key replacing an earlier one on the same booking takes over its share of the budget
+    let replaced = storage::get_session_key(env, booking_id)
+        .map(|key| key.spend_limit - key.spent)
+        .unwrap_or(0);
+    let budget = storage::get_session_key_budget(env, user, &booking.token)
+        .checked_sub(replaced)
+        .and_then(|budget| budget.checked_add(spend_limit))
+        .ok_or(VaultError::Overflow)?;
+    approve_session_key_budget(env, user, &booking.token, budget);
+
+    storage::set_session_key(
+        env,
+        booking_id,
+        &SessionKey {
+            key: session_key.clone(),
+            spend_limit,
+            spent: 0,
+        },
+    );
+    events::session_key_registered(env, booking_id, session_key, spend_limit);
+
+    Ok(())
+}
+
+/// Revoke the session key of a booking and release its unspent limit (User-only).
+pub fn revoke_session_key(env: &Env, user: &Address, booking_id: u64) -> Result<(), VaultError> {
+    user.require_auth();
+
+    let booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+
+    if booking.user != *user {
+        return Err(VaultError::NotAuthorized);
+    }
+
+    let session_key =
+        storage::get_session_key(env, booking_id).ok_or(VaultError::SessionKeyNotFound)?;
+
+    let budget = storage::get_session_key_budget(env, user, &booking.token)
+        - (session_key.spend_limit - session_key.spent);
+    approve_session_key_budget(env, user, &booking.token, budget);
+
+    storage::remove_session_key(env, booking_id);
+    events::session_key_revoked(env, booking_id, &session_key.key);
+
+    Ok(())
+}
+
+/// Re-approve the vault for the combined session key budget of `user` in `token` (User-only).
+/// The approval lapses after SESSION_KEY_ALLOWANCE_LEDGERS, so long-lived keys need renewing.
+pub fn renew_session_key_allowance(
+    env: &Env,
+    user: &Address,
+    token: &Address,
+) -> Result<(), VaultError> {
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+
+    user.require_auth();
+
+    let budget = storage::get_session_key_budget(env, user, token);
+    if budget <= 0 {
+        return Err(VaultError::SessionKeyNotFound);
+    }
+    approve_session_key_budget(env, user, token, budget);
+
+    Ok(())
+}
+
+/// Drop the state a booking only needs while `Pending`: its duration reports and attestation,
+/// and its session key, whose unspent limit leaves the user's budget. The token allowance
+/// itself can only shrink with the user's signature; it is trimmed to the budget on the
+/// user's next registration, revocation or renewal, and no key can draw on the excess.
+fn clear_pending_state(env: &Env, booking: &BookingRecord) {
+    storage::clear_duration_state(env, booking.id);
+
+    if let Some(session_key) = storage::get_session_key(env, booking.id) {
+        let budget = storage::get_session_key_budget(env, &booking.user, &booking.token)
+            - (session_key.spend_limit - session_key.spent);
+        storage::set_session_key_budget(env, &booking.user, &booking.token, budget);
+        storage::remove_session_key(env, booking.id);
+        events::session_key_revoked(env, booking.id, &session_key.key);
+    }
+}
+
+/// Record the combined session key budget of `user` in `token` and approve the vault for it.
+fn approve_session_key_budget(env: &Env, user: &Address, token: &Address, budget: i128) {
+    let live_until_ledger = if budget > 0 {
+        env.ledger()
+            .sequence()
+            .saturating_add(SESSION_KEY_ALLOWANCE_LEDGERS)
+    } else {
+        env.ledger().sequence()
+    };
+    token::Client::new(env, token).approve(
+        user,
+        &env.current_contract_address(),
+        &budget,
+        &live_until_ledger,
+    );
+    storage::set_session_key_budget(env, user, token, budget);
+}
+
+/// Acknowledge that a live session is still streaming (User or session key).
+/// Leaves an on-chain heartbeat without touching escrowed funds.
+pub fn acknowledge_session(env: &Env, caller: &Address, booking_id: u64) -> Result<(), VaultError> {
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+
+    caller.require_auth();
+
+    let booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+
+    if booking.user != *caller {
+        get_live_session_key(env, &booking, caller)?;
+    }
+
+    if booking.status != BookingStatus::Pending {
+        return Err(VaultError::BookingNotPending);
+    }
+
+    if booking.started_at.is_none() {
+        return Err(VaultError::SessionNotStarted);
+    }
+
+    events::session_acknowledged(env, booking_id, caller, env.ledger().timestamp());
+
+    Ok(())
+}
+
+/// Load the session key of a booking, checking it belongs to `signer` and has not expired.
+fn get_live_session_key(
+    env: &Env,
+    booking: &BookingRecord,
+    signer: &Address,
+) -> Result<SessionKey, VaultError> {
+    let session_key = storage::get_session_key(env, booking.id)
+        .filter(|session_key| session_key.key == *signer)
+        .ok_or(VaultError::NotAuthorized)?;
+
+    if is_session_key_expired(env, booking) {
+        return Err(VaultError::SessionKeyExpired);
+    }
+
+    Ok(session_key)
+}
+
+/// Session keys lapse once the booked duration has elapsed since the session started.
+fn is_session_key_expired(env: &Env, booking: &BookingRecord) -> bool {
+    match booking.started_at {
+        Some(started_at) => {
+            env.ledger().timestamp() > started_at.saturating_add(booking.max_duration)
+        }
+        None => false,
+    }
+}
+
+pub fn finalize_session(
+    env: &Env,
+    oracle: &Address,
+    booking_id: u64,
+    actual_duration: u64,
+) -> Result<(), VaultError> {
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+
+    // 1. Require an Oracle role holder, unless only signed receipts are trusted
+    access_control::require_role(env, Role::Oracle, oracle)?;
+    if storage::are_receipts_required(env) {
+        return Err(VaultError::ReceiptRequired);
+    }
+    if storage::get_oracle_quorum(env) > 0 {
+        return Err(VaultError::OracleQuorumRequired);
+    }
+
+    settle_session(env, booking_id, actual_duration)
+}
+
+/// Finalize a session from an ed25519-signed usage receipt (callable by anyone).
+/// `signature` must be made by a registered oracle key over [`receipt_message`].
+/// An invalid signature aborts the transaction.
+pub fn finalize_with_receipt(
+    env: &Env,
+    booking_id: u64,
+    actual_duration: u64,
+    nonce: u64,
+    public_key: &BytesN<32>,
+    signature: &BytesN<64>,
+) -> Result<(), VaultError> {
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+
+    // 1. A configured oracle set outranks any single signer
+    if storage::get_oracle_quorum(env) > 0 {
+        return Err(VaultError::OracleQuorumRequired);
+    }
+
+    // 2. The signer must be a registered oracle key and the nonce fresh
+    if !storage::get_oracle_keys(env).contains(public_key) {
+        return Err(VaultError::UnknownOracleKey);
+    }
+    if storage::is_receipt_nonce_used(env, nonce) {
+        return Err(VaultError::ReceiptNonceUsed);
+    }
+
+    // 3. Verify the signature over the receipt
+    let message = receipt_message(env, booking_id, actual_duration, nonce);
+    env.crypto().ed25519_verify(public_key, &message, signature);
+
+    storage::mark_receipt_nonce_used(env, nonce);
+    settle_session(env, booking_id, actual_duration)?;
+    events::receipt_accepted(env, booking_id, public_key, nonce);
+
+    Ok(())
+}
+
+/// Maximum number of oracles in the reporting set.
+const MAX_ORACLES: u32 = 10;
+
+/// Configure the oracles that report session durations (Admin role only).
+/// A session finalizes once `quorum` of them have reported and their reports lie within
+/// `tolerance` seconds of each other. From then on `finalize_session` is disabled.
+/// An empty set with a zero quorum hands finalization back to the single Oracle.
+/// A set can't be configured while receipts are required, since its reports are unsigned.
+pub fn set_oracle_set(
+    env: &Env,
+    caller: &Address,
+    oracles: &Vec<Address>,
+    quorum: u32,
+    tolerance: u64,
+) -> Result<(), VaultError> {
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
+
+    // An empty set with a zero quorum disables the set; otherwise the quorum must be reachable
+    if quorum == 0 {
+        if !oracles.is_empty() {
+            return Err(VaultError::InvalidOracleSet);
+        }
+    } else if quorum > oracles.len() || oracles.len() > MAX_ORACLES {
+        return Err(VaultError::InvalidOracleSet);
+    } else if storage::are_receipts_required(env) {
+        return Err(VaultError::ReceiptRequired);
+    }
+    for (index, oracle) in oracles.iter().enumerate() {
+        if oracles.first_index_of(&oracle) != Some(index as u32) {
+            return Err(VaultError::InvalidOracleSet);
+        }
+    }
+
+    storage::set_oracle_set(env, oracles, quorum, tolerance);
+    events::oracle_set_updated(env, oracles.len(), quorum, tolerance);
+    Ok(())
+}
+
+/// Report how long a session ran (Oracle set member only).
+/// Once a quorum of current members has reported, the session is finalized on the median
+/// report, or flagged for dispute resolution if the reports spread wider than the tolerance.
+/// Returns true if this report finalized the session.
+pub fn report_duration(
+    env: &Env,
+    oracle: &Address,
+    booking_id: u64,
+    duration: u64,
+) -> Result<bool, VaultError> {
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+
+    // 1. Require a member of the oracle set; unsigned reports can't bill time once
+    //    receipts are required
+    oracle.require_auth();
+    let oracles = storage::get_oracle_set(env);
+    if !oracles.contains(oracle) {
+        return Err(VaultError::NotOracle);
+    }
+    if storage::are_receipts_required(env) {
+        return Err(VaultError::ReceiptRequired);
+    }
+
+    // 2. The booking must still be awaiting settlement
+    let booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+    if booking.status != BookingStatus::Pending {
+        return Err(VaultError::BookingNotPending);
+    }
+    if storage::is_duration_disputed(env, booking_id) {
+        return Err(VaultError::DurationDisputed);
+    }
+    check_duration(env, &booking, duration)?;
+
+    // 3. Record the report, one per oracle
+    let mut reports = storage::get_duration_reports(env, booking_id);
+    if reports.iter().any(|report| report.oracle == *oracle) {
+        return Err(VaultError::DurationAlreadyReported);
+    }
+    reports.push_back(DurationReport {
+        oracle: oracle.clone(),
+        duration,
+    });
+    storage::set_duration_reports(env, booking_id, &reports);
+    events::duration_reported(env, booking_id, oracle, duration);
+
+    // 4. Wait for a quorum; reports from oracles removed from the set no longer count
+    let mut durations: Vec<u64> = Vec::new(env);
+    for report in reports
+        .iter()
+        .filter(|report| oracles.contains(&report.oracle))
+    {
+        let index = durations
+            .iter()
+            .position(|existing| existing > report.duration)
+            .unwrap_or(durations.len() as usize);
+        durations.insert(index as u32, report.duration);
+    }
+    if durations.len() < storage::get_oracle_quorum(env) {
+        return Ok(false);
+    }
+
+    // 5. Reports that disagree too much are left to dispute resolution
+    let min_duration = durations.first_unchecked();
+    let max_duration = durations.last_unchecked();
+    if max_duration - min_duration > storage::get_duration_tolerance(env) {
+        storage::set_duration_disputed(env, booking_id);
+        events::duration_disputed(env, booking_id, min_duration, max_duration);
+        return Ok(false);
+    }
+
+    // 6. Settle on the median (the lower one for an even number of reports)
+    let median = durations.get_unchecked((durations.len() - 1) / 2);
+    settle_session(env, booking_id, median)?;
+    Ok(true)
+}
+
+/// Domain tag leading every usage receipt, so its signature means nothing elsewhere.
+pub const RECEIPT_DOMAIN: &[u8] = b"skillsphere:usage-receipt:v1";
+
+/// Bytes an oracle signs for a usage receipt:
+/// `RECEIPT_DOMAIN || vault address (XDR) || booking_id || actual_duration || nonce`,
+/// with the numbers as big-endian u64s. Binding the vault address stops a receipt
+/// from settling the same booking id on another deployment.
+pub fn receipt_message(env: &Env, booking_id: u64, actual_duration: u64, nonce: u64) -> Bytes {
+    let mut message = Bytes::from_slice(env, RECEIPT_DOMAIN);
+    message.append(&env.current_contract_address().to_xdr(env));
+    message.extend_from_array(&booking_id.to_be_bytes());
+    message.extend_from_array(&actual_duration.to_be_bytes());
+    message.extend_from_array(&nonce.to_be_bytes());
+    message
+}
+
+/// A billed duration can't exceed the booked time or the time elapsed since the session started,
+/// nor fall short of what the Oracle already attested (and the expert may have streamed).
+/// A session that never started can only settle as a no-show (zero duration).
+fn check_duration(env: &Env, booking: &BookingRecord, duration: u64) -> Result<(), VaultError> {
+    let elapsed = match booking.started_at {
+        Some(started_at) => env.ledger().timestamp().saturating_sub(started_at),
+        None => 0,
+    };
+    if duration > booking.max_duration || duration > elapsed {
+        return Err(VaultError::InvalidDuration);
+    }
+    if duration < attested_duration(env, booking) {
+        return Err(VaultError::InvalidDuration);
+    }
+    Ok(())
+}
+
+/// Seconds of a started session the Oracle has attested as live; zero before the first heartbeat.
+fn attested_duration(env: &Env, booking: &BookingRecord) -> u64 {
+    match (
+        booking.started_at,
+        storage::get_session_attested_at(env, booking.id),
+    ) {
+        (Some(started_at), Some(attested_at)) => attested_at.saturating_sub(started_at),
+        _ => 0,
+    }
+}
+
+/// Settle a Pending booking for `actual_duration` seconds, without authorization checks.
+/// The duration can't exceed the booked time or the time elapsed since the session started.
+fn settle_session(env: &Env, booking_id: u64, actual_duration: u64) -> Result<(), VaultError> {
+    // 2. Get booking and verify it exists
+    let booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+
+    // 3. Verify booking is in Pending status
+    if booking.status != BookingStatus::Pending {
+        return Err(VaultError::BookingNotPending);
+    }
+
+    // 4. Bound the billed time by what actually elapsed
+    check_duration(env, &booking, actual_duration)?;
+
+    // 5. Calculate payments.
+    // rate_per_second is stored in atomic units of the payment token, so this
+    // multiplication is safe for any token precision as long as the product fits i128.
+    let expert_pay = booking
+        .rate_per_second
+        .checked_mul(actual_duration as i128)
+        .ok_or(VaultError::Overflow)?
+        .min(booking.total_deposit);
+    let refund = booking.total_deposit - expert_pay;
+
+    // Anything the expert already streamed out is deducted from the final payout.
+    // Streaming stops at the Oracle's last attestation, which the duration can't undercut.
+    let remaining_pay = expert_pay - booking.withdrawn_amount;
+
+    // Ensure calculations are valid
+    if remaining_pay < 0 || refund < 0 {
+        return Err(VaultError::InvalidAmount);
+    }
+
+    // 6. Get token contract
+    let token_client = token::Client::new(env, &booking.token);
+    let contract_address = env.current_contract_address();
+
+    // 7. Execute transfers, releasing everything still escrowed for the booking
+    release_escrow(env, &booking.token, remaining_escrow(&booking)?)?;
+    // Pay expert the part of their earnings not yet withdrawn, minus the platform fee
+    pay_expert(
+        env,
+        &token_client,
+        booking_id,
+        &booking.expert,
+        remaining_pay,
+    )?;
+
+    // Refund user
+    if refund > 0 {
+        token_client.transfer(&contract_address, &booking.user, &refund);
+    }
+
+    // 8. Update booking status to Complete
+    storage::update_booking_status(env, booking_id, BookingStatus::Complete);
+    storage::set_expert_payout(env, booking_id, expert_pay);
+    clear_pending_state(env, &booking);
+
+    // 9. Emit SessionFinalized event
+    events::session_finalized(env, booking_id, actual_duration, expert_pay);
+    report_outcome(env, booking_id, &booking.expert, SessionOutcome::Completed);
+
+    Ok(())
+}
+
+/// Withdraw earnings streamed from a live session (Expert-only).
+/// The expert may claim `rate_per_second * (attested_at - started_at)`, capped at
+/// `total_deposit`, minus whatever has already been withdrawn. Only the Oracle moves
+/// `attested_at` forward. Returns the amount transferred after fees.
+pub fn withdraw_streamed(env: &Env, expert: &Address, booking_id: u64) -> Result<i128, VaultError> {
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+
+    // 1. Require expert authorization
+    expert.require_auth();
+
+    // 2. Get booking and verify it exists
+    let mut booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+
+    // 3. Verify the caller is the expert in the booking
+    if booking.expert != *expert {
+        return Err(VaultError::NotAuthorized);
+    }
+
+    // 4. Verify booking is live
+    if booking.status != BookingStatus::Pending {
+        return Err(VaultError::BookingNotPending);
+    }
+    let started_at = booking.started_at.ok_or(VaultError::SessionNotStarted)?;
+
+    // 5. Calculate what has been earned up to the Oracle's last attestation,
+    //    never more than what was deposited
+    let attested_at = storage::get_session_attested_at(env, booking_id).unwrap_or(started_at);
+    let earned = streamed_earnings(&booking, started_at, attested_at)?;
+
+    let amount = earned
+        .checked_sub(booking.withdrawn_amount)
+        .ok_or(VaultError::Overflow)?;
+    if amount <= 0 {
+        return Err(VaultError::NothingToWithdraw);
+    }
+
+    // 6. Transfer the claimable amount to the expert, minus the platform fee
+    release_escrow(env, &booking.token, amount)?;
+    let token_client = token::Client::new(env, &booking.token);
+    let net = pay_expert(env, &token_client, booking_id, &booking.expert, amount)?;
+
+    // 7. Track the withdrawal so finalization only settles the remainder
+    booking.withdrawn_amount = earned;
+    storage::update_booking(env, &booking);
+
+    // 8. Emit event
+    events::session_withdrawn(env, booking_id, amount, earned);
+
+    Ok(net)
+}
+
+/// Maximum number of ed25519 keys allowed to sign usage receipts.
+const MAX_ORACLE_KEYS: u32 = 10;
+
+/// Register an ed25519 public key whose receipts can finalize sessions (Admin role only).
+pub fn add_oracle_key(
+    env: &Env,
+    caller: &Address,
+    public_key: &BytesN<32>,
+) -> Result<(), VaultError> {
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
+
+    let mut keys = storage::get_oracle_keys(env);
+    if keys.contains(public_key) {
+        return Ok(());
+    }
+    if keys.len() >= MAX_ORACLE_KEYS {
+        return Err(VaultError::TooManyOracleKeys);
+    }
+    keys.push_back(public_key.clone());
+    storage::set_oracle_keys(env, &keys);
+    events::oracle_key_updated(env, public_key, true);
+
+    Ok(())
+}
+
+/// Stop accepting receipts signed by `public_key` (Admin role only).
+pub fn remove_oracle_key(
+    env: &Env,
+    caller: &Address,
+    public_key: &BytesN<32>,
+) -> Result<(), VaultError> {
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
+
+    let mut keys = storage::get_oracle_keys(env);
+    let index = keys
+        .first_index_of(public_key)
+        .ok_or(VaultError::UnknownOracleKey)?;
+    keys.remove(index);
+    storage::set_oracle_keys(env, &keys);
+    events::oracle_key_updated(env, public_key, false);
+
+    Ok(())
+}
+
+/// Require (or stop requiring) signed receipts for finalization (Admin role only).
+/// While required, `finalize_session` and `report_duration` are disabled and the Oracle
+/// address alone can't bill time. Not available while an oracle set is configured.
+pub fn set_receipts_required(
+    env: &Env,
+    caller: &Address,
+    required: bool,
+) -> Result<(), VaultError> {
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
+
+    if required && storage::get_oracle_keys(env).is_empty() {
+        return Err(VaultError::UnknownOracleKey);
+    }
+    if required && storage::get_oracle_quorum(env) > 0 {
+        return Err(VaultError::OracleQuorumRequired);
+    }
+    storage::set_receipts_required(env, required);
+    events::receipts_required_updated(env, required);
+
+    Ok(())
+}
+
+/// What the expert has earned on a live session: `rate_per_second * (until - started_at)`,
+/// never more than what was deposited.
+fn streamed_earnings(
+    booking: &BookingRecord,
+    started_at: u64,
+    until: u64,
+) -> Result<i128, VaultError> {
+    let elapsed = until.saturating_sub(started_at);
+    Ok(booking
+        .rate_per_second
+        .checked_mul(elapsed as i128)
+        .ok_or(VaultError::Overflow)?
+        .min(booking.total_deposit))
+}
+
+/// End a started session before the Oracle finalizes it (User-only).
+/// Pays the expert for the elapsed time and refunds the rest of the escrow right away.
+pub fn end_session_early(env: &Env, user: &Address, booking_id: u64) -> Result<(), VaultError> {
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+
+    // 1. Require user authorization
+    user.require_auth();
+
+    // 2. Get booking and verify it exists
+    let booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+
+    // 3. Verify the caller is the booking owner
+    if booking.user != *user {
+        return Err(VaultError::NotAuthorized);
+    }
+
+    // 4. Settle at the current time
+    let (earned, refund) = settle_early(env, &booking, BookingStatus::EndedByUser)?;
+    events::session_ended_by_user(env, booking_id, earned, refund);
+    report_outcome(
+        env,
+        booking_id,
+        &booking.expert,
+        SessionOutcome::EndedByUser,
+    );
 
-    Ok(booking_id)
+    Ok(())
 }
 
-pub fn top_up_session(
+/// End a started session before the Oracle finalizes it (Expert-only).
+/// The expert is paid only for the elapsed time and the user refunded the rest.
+pub fn expert_end_session(
     env: &Env,
-    user: &Address,
+    expert: &Address,
     booking_id: u64,
-    additional_duration: u64,
+    reason: Option<u32>,
 ) -> Result<(), VaultError> {
     if storage::is_paused(env) {
         return Err(VaultError::ContractPaused);
     }
 
-    // Require authorization from the user
-    user.require_auth();
+    // 1. Require expert authorization
+    expert.require_auth();
 
-    // Get booking and verify it exists
-    let mut booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+    // 2. Get booking and verify it exists
+    let booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
 
-    // Verify the caller is the booking owner
-    if booking.user != *user {
+    // 3. Verify the caller is the expert in the booking
+    if booking.expert != *expert {
         return Err(VaultError::NotAuthorized);
     }
 
-    // Verify booking is in Pending status
+    // 4. Settle at the current time
+    let (earned, refund) = settle_early(env, &booking, BookingStatus::EndedByExpert)?;
+    events::session_ended_by_expert(env, booking_id, earned, refund, reason);
+    report_outcome(
+        env,
+        booking_id,
+        &booking.expert,
+        SessionOutcome::EndedByExpert,
+    );
+
+    Ok(())
+}
+
+/// Close a live session at the current time: pay the expert what was earned so far
+/// (minus anything already streamed), refund the rest to the user and move the booking
+/// to `status`. Returns the expert's total earnings and the user's refund.
+fn settle_early(
+    env: &Env,
+    booking: &BookingRecord,
+    status: BookingStatus,
+) -> Result<(i128, i128), VaultError> {
+    // Verify the session is live
     if booking.status != BookingStatus::Pending {
         return Err(VaultError::BookingNotPending);
     }
+    let started_at = booking.started_at.ok_or(VaultError::SessionNotStarted)?;
 
-    // Calculate extra cost
-    let extra_cost = booking
-        .rate_per_second
-        .checked_mul(additional_duration as i128)
+    // Split the escrow at the current time
+    let earned = streamed_earnings(booking, started_at, env.ledger().timestamp())?;
+    let remaining_pay = earned
+        .checked_sub(booking.withdrawn_amount)
         .ok_or(VaultError::Overflow)?;
-
-    if extra_cost <= 0 {
-        return Err(VaultError::InvalidAmount);
+    let refund = booking.total_deposit - earned;
+
+    // Execute transfers, releasing everything still escrowed for the booking
+    release_escrow(env, &booking.token, remaining_escrow(booking)?)?;
+    let token_client = token::Client::new(env, &booking.token);
+    pay_expert(
+        env,
+        &token_client,
+        booking.id,
+        &booking.expert,
+        remaining_pay,
+    )?;
+    if refund > 0 {
+        token_client.transfer(&env.current_contract_address(), &booking.user, &refund);
     }
 
-    // Get the token contract
-    let token_address = storage::get_token(env);
-    let token_client = token::Client::new(env, &token_address);
+    storage::update_booking_status(env, booking.id, status);
+    storage::set_expert_payout(env, booking.id, earned);
+    clear_pending_state(env, booking);
 
-    // Transfer extra tokens from user to this contract
-    let contract_address = env.current_contract_address();
-    token_client.transfer(user, &contract_address, &extra_cost);
+    Ok((earned, refund))
+}
 
-    // Update booking
-    booking.total_deposit = booking
-        .total_deposit
-        .checked_add(extra_cost)
-        .ok_or(VaultError::Overflow)?;
-    booking.max_duration = booking
-        .max_duration
-        .checked_add(additional_duration)
-        .ok_or(VaultError::Overflow)?;
+/// Upper bound for the platform fee: 10%.
+const MAX_FEE_BPS: u32 = 1_000;
+const BPS_DENOMINATOR: i128 = 10_000;
 
-    // Save booking
-    storage::save_booking(env, &booking);
+/// Configure the platform fee and the treasury that receives it (Admin role only).
+pub fn set_fee_config(
+    env: &Env,
+    caller: &Address,
+    fee_bps: u32,
+    treasury: &Address,
+) -> Result<(), VaultError> {
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
 
-    // Emit event
-    events::session_topped_up(env, booking_id, additional_duration, extra_cost);
+    if fee_bps > MAX_FEE_BPS {
+        return Err(VaultError::FeeTooHigh);
+    }
+
+    if let Some(old_treasury) = storage::get_treasury(env).filter(|old| old != treasury) {
+        access_control::revoke_role(env, Role::Treasurer, &old_treasury, caller);
+    }
+    storage::set_fee_bps(env, fee_bps);
+    storage::set_treasury(env, treasury);
+    access_control::grant_role(env, Role::Treasurer, treasury, caller);
+    events::fee_config_updated(env, fee_bps, treasury);
 
     Ok(())
 }
 
-pub fn finalize_session(
+/// Send all platform fees accrued in `token` to the treasury (Treasurer role only).
+pub fn withdraw_fees(env: &Env, treasurer: &Address, token: &Address) -> Result<i128, VaultError> {
+    storage::get_treasury(env).ok_or(VaultError::TreasuryNotSet)?;
+    access_control::require_role(env, Role::Treasurer, treasurer)?;
+
+    let amount = storage::get_accrued_fees(env, token);
+    if amount <= 0 {
+        return Err(VaultError::NothingToWithdraw);
+    }
+
+    storage::set_accrued_fees(env, token, 0);
+
+    let token_client = token::Client::new(env, token);
+    token_client.transfer(&env.current_contract_address(), treasurer, &amount);
+
+    events::fees_withdrawn(env, treasurer, token, amount);
+
+    Ok(amount)
+}
+
+/// Pay an expert `gross` out of escrow, keeping the platform fee in the vault for the treasury.
+/// Returns the net amount transferred to the expert.
+fn pay_expert(
     env: &Env,
+    token_client: &token::Client,
     booking_id: u64,
-    actual_duration: u64,
-) -> Result<(), VaultError> {
-    if storage::is_paused(env) {
-        return Err(VaultError::ContractPaused);
+    expert: &Address,
+    gross: i128,
+) -> Result<i128, VaultError> {
+    if gross <= 0 {
+        return Ok(0);
     }
 
-    // 1. Require Oracle authorization
-    let oracle = storage::get_oracle(env);
-    oracle.require_auth();
+    let fee = gross
+        .checked_mul(storage::get_fee_bps(env) as i128)
+        .ok_or(VaultError::Overflow)?
+        / BPS_DENOMINATOR;
+    let net = gross - fee;
 
-    // 2. Get booking and verify it exists
-    let booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+    if net > 0 {
+        token_client.transfer(&env.current_contract_address(), expert, &net);
+    }
 
-    // 3. Verify booking is in Pending status
-    if booking.status != BookingStatus::Pending {
-        return Err(VaultError::BookingNotPending);
+    if fee > 0 {
+        let accrued = storage::get_accrued_fees(env, &token_client.address)
+            .checked_add(fee)
+            .ok_or(VaultError::Overflow)?;
+        storage::set_accrued_fees(env, &token_client.address, accrued);
+        events::fee_collected(env, booking_id, fee);
     }
 
-    // 4. Calculate payments.
-    // rate_per_second is stored in atomic units of the payment token, so this
-    // multiplication is safe for any token precision as long as the product fits i128.
-    let expert_pay = booking
-        .rate_per_second
-        .checked_mul(actual_duration as i128)
+    Ok(net)
+}
+
+/// Add a deposit to the running total of escrow locked in `token`.
+fn lock_escrow(env: &Env, token: &Address, amount: i128) -> Result<(), VaultError> {
+    let locked = storage::get_locked_escrow(env, token)
+        .checked_add(amount)
         .ok_or(VaultError::Overflow)?;
-    let refund = booking.total_deposit - expert_pay;
+    storage::set_locked_escrow(env, token, locked);
+    Ok(())
+}
 
-    // Ensure calculations are valid
-    if expert_pay < 0 || refund < 0 {
-        return Err(VaultError::InvalidAmount);
+/// Take a payout or refund off the running total of escrow locked in `token`.
+/// Fails rather than paying out more than the vault recorded as deposited.
+fn release_escrow(env: &Env, token: &Address, amount: i128) -> Result<(), VaultError> {
+    let locked = storage::get_locked_escrow(env, token);
+    if amount > locked {
+        return Err(VaultError::InsufficientEscrow);
     }
+    storage::set_locked_escrow(env, token, locked - amount);
+    Ok(())
+}
 
-    // 5. Get token contract
-    let token_address = storage::get_token(env);
-    let token_client = token::Client::new(env, &token_address);
-    let contract_address = env.current_contract_address();
+/// What the vault owes in `token` (open escrow and unwithdrawn fees) next to its balance.
+pub fn get_token_liabilities(env: &Env, token: &Address) -> TokenLiabilities {
+    let locked_escrow = storage::get_locked_escrow(env, token);
+    let accrued_fees = storage::get_accrued_fees(env, token);
+    let balance = token::Client::new(env, token).balance(&env.current_contract_address());
+    TokenLiabilities {
+        token: token.clone(),
+        locked_escrow,
+        accrued_fees,
+        balance,
+        surplus: balance - locked_escrow - accrued_fees,
+    }
+}
 
-    // 6. Execute transfers
-    // Pay expert
-    if expert_pay > 0 {
-        token_client.transfer(&contract_address, &booking.expert, &expert_pay);
+/// Solvency snapshot for every token ever allowlisted.
+pub fn get_liabilities(env: &Env) -> Vec<TokenLiabilities> {
+    let mut liabilities = Vec::new(env);
+    for token in storage::get_payment_tokens(env) {
+        liabilities.push_back(get_token_liabilities(env, &token));
     }
+    liabilities
+}
 
-    // Refund user
-    if refund > 0 {
-        token_client.transfer(&contract_address, &booking.user, &refund);
+/// Move `token` held above the vault's liabilities to `to` (Admin role only).
+/// Escrow and accrued fees are never touched. Returns the amount swept.
+pub fn sweep_excess(
+    env: &Env,
+    caller: &Address,
+    token: &Address,
+    to: &Address,
+) -> Result<i128, VaultError> {
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
+
+    let amount = get_token_liabilities(env, token).surplus;
+    if amount <= 0 {
+        return Err(VaultError::NothingToWithdraw);
     }
 
-    // 7. Update booking status to Complete
-    storage::update_booking_status(env, booking_id, BookingStatus::Complete);
+    let token_client = token::Client::new(env, token);
+    token_client.transfer(&env.current_contract_address(), to, &amount);
+    events::excess_swept(env, token, to, amount);
 
-    // 8. Emit SessionFinalized event
-    events::session_finalized(env, booking_id, actual_duration, expert_pay);
+    Ok(amount)
+}
 
-    Ok(())
+/// Escrow still held by the vault for a booking, after any streamed withdrawals.
+fn remaining_escrow(booking: &BookingRecord) -> Result<i128, VaultError> {
+    booking
+        .total_deposit
+        .checked_sub(booking.withdrawn_amount)
+        .ok_or(VaultError::Overflow)
 }
 
 /// 24 hours in seconds
@@ -284,36 +1237,89 @@ pub fn reclaim_stale_session(env: &Env, user: &Address, booking_id: u64) -> Resu
         return Err(VaultError::BookingNotPending);
     }
 
-    // 5. Check if 24 hours have passed since booking creation
+    // 5. Check if 24 hours have passed since the reserved slot ended,
+    //    or since booking creation when no slot was reserved
     let current_time = env.ledger().timestamp();
-    if current_time <= booking.created_at + RECLAIM_TIMEOUT {
+    let stale_from = reserved_slot_end(env, booking_id).unwrap_or(booking.created_at);
+    if current_time <= stale_from.saturating_add(RECLAIM_TIMEOUT) {
         return Err(VaultError::ReclaimTooEarly);
     }
 
-    // 6. Transfer total_deposit back to user
-    let token_address = storage::get_token(env);
-    let token_client = token::Client::new(env, &token_address);
+    // 6. Return the remaining escrow. A reserved slot that was never used is forfeited
+    //    under the expert's cancellation policy, as if the user cancelled at the last moment;
+    //    a started session is refunded in full, since only the Oracle failed to settle it.
+    let remaining = remaining_escrow(&booking)?;
+    let refund = if booking.started_at.is_none() && reserved_slot_end(env, booking_id).is_some() {
+        apply_refund_bps(remaining, cancellation_refund_bps(env, booking_id))?
+    } else {
+        remaining
+    };
+    let forfeited = remaining - refund;
+    release_escrow(env, &booking.token, remaining)?;
+    let token_client = token::Client::new(env, &booking.token);
     let contract_address = env.current_contract_address();
-    token_client.transfer(&contract_address, &booking.user, &booking.total_deposit);
+    if refund > 0 {
+        token_client.transfer(&contract_address, &booking.user, &refund);
+    }
+    pay_expert(env, &token_client, booking_id, &booking.expert, forfeited)?;
 
     // 7. Update booking status to Reclaimed
     storage::update_booking_status(env, booking_id, BookingStatus::Reclaimed);
+    clear_pending_state(env, &booking);
 
     // 8. Emit event
-    events::session_reclaimed(env, booking_id, booking.total_deposit);
+    events::session_reclaimed(env, booking_id, refund, forfeited);
+    // A stale booking doesn't show whether the expert, the user or the Oracle failed, so
+    // the outcome is neutral; penalties are left to the dispute flow
+    report_outcome(env, booking_id, &booking.expert, SessionOutcome::Reclaimed);
 
     Ok(())
 }
 
-/// Mark a session as started (Oracle-only).
+/// End of the calendar slot reserved for a booking, if a calendar is configured and has one.
+fn reserved_slot_end(env: &Env, booking_id: u64) -> Option<u64> {
+    let calendar = storage::get_calendar_address(env)?;
+    let reservation: Option<CalendarReservation> = env.invoke_contract(
+        &calendar,
+        &Symbol::new(env, "get_reservation"),
+        soroban_sdk::vec![env, booking_id.into_val(env)],
+    );
+    reservation.map(|reservation| reservation.end_ts)
+}
+
+/// Share of the deposit the expert's cancellation policy refunds right now, in basis points.
+/// Everything is refunded when no calendar is configured.
+fn cancellation_refund_bps(env: &Env, booking_id: u64) -> u32 {
+    match storage::get_calendar_address(env) {
+        Some(calendar) => env.invoke_contract::<u32>(
+            &calendar,
+            &Symbol::new(env, "get_cancellation_refund_bps"),
+            soroban_sdk::vec![env, booking_id.into_val(env)],
+        ),
+        None => BPS_DENOMINATOR as u32,
+    }
+}
+
+/// Part of `amount` refunded at `refund_bps`, never more than all of it.
+fn apply_refund_bps(amount: i128, refund_bps: u32) -> Result<i128, VaultError> {
+    Ok(amount
+        .checked_mul(refund_bps.min(BPS_DENOMINATOR as u32) as i128)
+        .ok_or(VaultError::Overflow)?
+        / BPS_DENOMINATOR)
+}
+
+/// Mark a session as started (Oracle role only).
 /// Once started, the user can no longer cancel the booking.
-pub fn mark_session_started(env: &Env, booking_id: u64) -> Result<(), VaultError> {
+pub fn mark_session_started(
+    env: &Env,
+    oracle: &Address,
+    booking_id: u64,
+) -> Result<(), VaultError> {
     if storage::is_paused(env) {
         return Err(VaultError::ContractPaused);
     }
 
-    let oracle = storage::get_oracle(env);
-    oracle.require_auth();
+    access_control::require_role(env, Role::Oracle, oracle)?;
 
     let booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
 
@@ -328,6 +1334,40 @@ pub fn mark_session_started(env: &Env, booking_id: u64) -> Result<(), VaultError
     Ok(())
 }
 
+/// Attest that a started session is still live (Oracle role only).
+/// Streaming withdrawals accrue up to the latest attestation, and the session can't be
+/// finalized for less time than attested. Unavailable where the single Oracle can't bill
+/// time: while receipts are required or an oracle set is configured.
+pub fn attest_session(env: &Env, oracle: &Address, booking_id: u64) -> Result<(), VaultError> {
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+
+    access_control::require_role(env, Role::Oracle, oracle)?;
+    if storage::are_receipts_required(env) {
+        return Err(VaultError::ReceiptRequired);
+    }
+    if storage::get_oracle_quorum(env) > 0 {
+        return Err(VaultError::OracleQuorumRequired);
+    }
+
+    let booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
+    if booking.status != BookingStatus::Pending {
+        return Err(VaultError::BookingNotPending);
+    }
+    let started_at = booking.started_at.ok_or(VaultError::SessionNotStarted)?;
+
+    // Attestations never reach past the booked duration
+    let attested_at = env
+        .ledger()
+        .timestamp()
+        .min(started_at.saturating_add(booking.max_duration));
+    storage::set_session_attested_at(env, booking_id, attested_at);
+    events::session_attested(env, booking_id, attested_at);
+
+    Ok(())
+}
+
 /// Cancel a pending booking and receive a full refund (User-only).
 /// Can only be cancelled if the Oracle has not yet marked it as started.
 pub fn cancel_booking(env: &Env, user: &Address, booking_id: u64) -> Result<(), VaultError> {
@@ -351,30 +1391,116 @@ pub fn cancel_booking(env: &Env, user: &Address, booking_id: u64) -> Result<(),
         return Err(VaultError::SessionAlreadyStarted);
     }
 
-    let token_address = storage::get_token(env);
-    let token_client = token::Client::new(env, &token_address);
+    // Apply the expert's cancellation policy for the reserved slot, if any
+    let refund = apply_refund_bps(
+        booking.total_deposit,
+        cancellation_refund_bps(env, booking_id),
+    )?;
+    let forfeited = booking.total_deposit - refund;
+    release_escrow(env, &booking.token, booking.total_deposit)?;
+
+    let token_client = token::Client::new(env, &booking.token);
     let contract_address = env.current_contract_address();
-    token_client.transfer(&contract_address, &booking.user, &booking.total_deposit);
+    if refund > 0 {
+        token_client.transfer(&contract_address, &booking.user, &refund);
+    }
+    pay_expert(env, &token_client, booking_id, &booking.expert, forfeited)?;
 
     storage::update_booking_status(env, booking_id, BookingStatus::Cancelled);
-    events::booking_cancelled(env, booking_id, booking.total_deposit);
+    clear_pending_state(env, &booking);
+    events::booking_cancelled(env, booking_id, refund, forfeited);
+
+    Ok(())
+}
 
+/// Point the vault at the reputation contract notified of session outcomes (Admin role only)
+pub fn set_reputation_address(
+    env: &Env,
+    caller: &Address,
+    reputation: &Address,
+) -> Result<(), VaultError> {
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
+    storage::set_reputation_address(env, reputation);
+    events::reputation_updated(env, reputation);
     Ok(())
 }
 
-pub fn transfer_admin(env: &Env, new_admin: &Address) -> Result<(), VaultError> {
-    let current_admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
-    current_admin.require_auth();
-    storage::set_admin(env, new_admin);
-    events::admin_transferred(env, &current_admin, new_admin);
+/// Report a booking's terminal outcome to the reputation contract, if configured.
+/// Best-effort: a failing reputation call (e.g. while it is paused) never blocks payouts.
+fn report_outcome(env: &Env, booking_id: u64, expert: &Address, outcome: SessionOutcome) {
+    let Some(reputation) = storage::get_reputation_address(env) else {
+        return;
+    };
+    let _ = env.try_invoke_contract::<(), soroban_sdk::Error>(
+        &reputation,
+        &Symbol::new(env, "record_session_outcome"),
+        soroban_sdk::vec![
+            env,
+            booking_id.into_val(env),
+            expert.into_val(env),
+            outcome.into_val(env),
+        ],
+    );
+}
+
+/// Point the vault at the calendar contract whose cancellation policies apply (Admin role only)
+pub fn set_calendar_address(
+    env: &Env,
+    caller: &Address,
+    calendar: &Address,
+) -> Result<(), VaultError> {
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
+    storage::set_calendar_address(env, calendar);
+    events::calendar_updated(env, calendar);
     Ok(())
 }
 
-pub fn set_oracle(env: &Env, new_oracle: &Address) -> Result<(), VaultError> {
+/// Nominate `new_admin` as the next admin (Admin-only).
+/// Nothing changes until the nominee calls `accept_admin`; a new proposal replaces any pending one.
+pub fn propose_admin(env: &Env, new_admin: &Address) -> Result<(), VaultError> {
     let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
-    admin.require_auth();
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+    access_control::propose_admin(env, &admin, new_admin);
+    events::admin_proposed(env, &admin, new_admin);
+    Ok(())
+}
+
+/// Complete a pending admin transfer (pending admin only).
+pub fn accept_admin(env: &Env) -> Result<(), VaultError> {
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+    let new_admin = access_control::accept_admin(env, &admin)?;
+    storage::set_admin(env, &new_admin);
+    events::admin_transferred(env, &admin, &new_admin);
+    Ok(())
+}
+
+/// Withdraw a pending admin proposal (Admin-only).
+pub fn cancel_admin_transfer(env: &Env) -> Result<(), VaultError> {
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+    let pending_admin = access_control::cancel_admin_transfer(env, &admin)?;
+    events::admin_transfer_cancelled(env, &admin, &pending_admin);
+    Ok(())
+}
+
+pub fn set_oracle(env: &Env, caller: &Address, new_oracle: &Address) -> Result<(), VaultError> {
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
     let old_oracle = storage::get_oracle(env);
+    if old_oracle != *new_oracle {
+        access_control::revoke_role(env, Role::Oracle, &old_oracle, caller);
+    }
     storage::set_oracle(env, new_oracle);
+    access_control::grant_role(env, Role::Oracle, new_oracle, caller);
     events::oracle_updated(env, &old_oracle, new_oracle);
     Ok(())
 }
@@ -400,17 +1526,27 @@ pub fn reject_session(env: &Env, expert: &Address, booking_id: u64) -> Result<()
         return Err(VaultError::BookingNotPending);
     }
 
-    // 5. Transfer total_deposit back to user
-    let token_address = storage::get_token(env);
-    let token_client = token::Client::new(env, &token_address);
+    // 5. A started session is settled or disputed, not rejected
+    if booking.started_at.is_some() {
+        return Err(VaultError::SessionAlreadyStarted);
+    }
+
+    // 6. Refund the escrow to the user
+    let refund = remaining_escrow(&booking)?;
+    release_escrow(env, &booking.token, refund)?;
+    let token_client = token::Client::new(env, &booking.token);
     let contract_address = env.current_contract_address();
-    token_client.transfer(&contract_address, &booking.user, &booking.total_deposit);
+    if refund > 0 {
+        token_client.transfer(&contract_address, &booking.user, &refund);
+    }
 
-    // 6. Update booking status to Rejected
+    // 7. Update booking status to Rejected
     storage::update_booking_status(env, booking_id, BookingStatus::Rejected);
+    clear_pending_state(env, &booking);
 
-    // 7. Emit event
+    // 8. Emit event
     events::session_rejected(env, booking_id, "Expert declined session");
+    report_outcome(env, booking_id, &booking.expert, SessionOutcome::Rejected);
 
     Ok(())
 }
@@ -419,6 +1555,7 @@ pub fn reject_session(env: &Env, expert: &Address, booking_id: u64) -> Result<()
 /// Used when the Oracle crashes or an unresolvable dispute occurs between user and expert.
 pub fn resolve_dispute(
     env: &Env,
+    caller: &Address,
     booking_id: u64,
     user_refund: i128,
     expert_pay: i128,
@@ -427,10 +1564,23 @@ pub fn resolve_dispute(
         return Err(VaultError::ContractPaused);
     }
 
-    // 1. Require admin authorization
-    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
-    admin.require_auth();
+    // 1. Require an Admin role holder; once approvers are configured only they can split escrow
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
+    if storage::get_dispute_threshold(env) > 0 {
+        return Err(VaultError::MultisigRequired);
+    }
+
+    apply_dispute_split(env, booking_id, user_refund, expert_pay)
+}
 
+/// Split a Pending booking's escrow between user and expert, without authorization checks.
+fn apply_dispute_split(
+    env: &Env,
+    booking_id: u64,
+    user_refund: i128,
+    expert_pay: i128,
+) -> Result<(), VaultError> {
     // 2. Get booking and verify it exists
     let mut booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
 
@@ -448,13 +1598,14 @@ pub fn resolve_dispute(
         .checked_add(expert_pay)
         .ok_or(VaultError::Overflow)?;
 
-    if total_split > booking.total_deposit {
+    if total_split > remaining_escrow(&booking)? {
         return Err(VaultError::InvalidAmount);
     }
+    // Whatever is not split stays locked until the remainder is recovered
+    release_escrow(env, &booking.token, total_split)?;
 
     // 5. Get token contract
-    let token_address = storage::get_token(env);
-    let token_client = token::Client::new(env, &token_address);
+    let token_client = token::Client::new(env, &booking.token);
     let contract_address = env.current_contract_address();
 
     // 6. Execute transfers
@@ -462,9 +1613,7 @@ pub fn resolve_dispute(
         token_client.transfer(&contract_address, &booking.user, &user_refund);
     }
 
-    if expert_pay > 0 {
-        token_client.transfer(&contract_address, &booking.expert, &expert_pay);
-    }
+    pay_expert(env, &token_client, booking_id, &booking.expert, expert_pay)?;
 
     // 7. Persist dispute split and transition booking to DisputedAndResolved
     booking.status = BookingStatus::DisputedAndResolved;
@@ -472,23 +1621,43 @@ pub fn resolve_dispute(
     booking.dispute_expert_pay = Some(expert_pay);
     booking.dispute_remainder_recovered = false;
     storage::update_booking(env, &booking);
+    clear_pending_state(env, &booking);
 
     // 8. Emit event
     events::dispute_resolved(env, booking_id, user_refund, expert_pay);
+    let outcome = if expert_pay >= user_refund {
+        SessionOutcome::DisputeWon
+    } else {
+        SessionOutcome::DisputeLost
+    };
+    report_outcome(env, booking_id, &booking.expert, outcome);
 
     Ok(())
 }
 
-/// Admin-only recovery path for disputed remainder left in vault after resolve_dispute.
-/// Recovers `total_deposit - dispute_user_refund - dispute_expert_pay` exactly once.
-pub fn recover_disputed_remainder(env: &Env, booking_id: u64) -> Result<i128, VaultError> {
+/// Admin-role recovery path for disputed remainder left in vault after resolve_dispute.
+/// Recovers `total_deposit - withdrawn_amount - dispute_user_refund - dispute_expert_pay` exactly once.
+pub fn recover_disputed_remainder(
+    env: &Env,
+    caller: &Address,
+    booking_id: u64,
+) -> Result<i128, VaultError> {
     if storage::is_paused(env) {
         return Err(VaultError::ContractPaused);
     }
 
-    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
-    admin.require_auth();
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
+    if storage::get_dispute_threshold(env) > 0 {
+        return Err(VaultError::MultisigRequired);
+    }
 
+    apply_remainder_recovery(env, booking_id)
+}
+
+/// Send a resolved booking's leftover escrow to the admin, without authorization checks.
+fn apply_remainder_recovery(env: &Env, booking_id: u64) -> Result<i128, VaultError> {
+    let admin = storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
     let mut booking = storage::get_booking(env, booking_id).ok_or(VaultError::BookingNotFound)?;
 
     if booking.status != BookingStatus::DisputedAndResolved {
@@ -502,8 +1671,7 @@ pub fn recover_disputed_remainder(env: &Env, booking_id: u64) -> Result<i128, Va
     let user_refund = booking.dispute_user_refund.unwrap_or(0);
     let expert_pay = booking.dispute_expert_pay.unwrap_or(0);
 
-    let remainder = booking
-        .total_deposit
+    let remainder = remaining_escrow(&booking)?
         .checked_sub(user_refund)
         .and_then(|v| v.checked_sub(expert_pay))
         .ok_or(VaultError::Overflow)?;
@@ -511,9 +1679,10 @@ pub fn recover_disputed_remainder(env: &Env, booking_id: u64) -> Result<i128, Va
     if remainder < 0 {
         return Err(VaultError::InvalidAmount);
     }
+    // The running total must still cover the remainder, so stale booking numbers can't drain it
+    release_escrow(env, &booking.token, remainder)?;
 
-    let token_address = storage::get_token(env);
-    let token_client = token::Client::new(env, &token_address);
+    let token_client = token::Client::new(env, &booking.token);
     let contract_address = env.current_contract_address();
 
     if remainder > 0 {
@@ -526,3 +1695,151 @@ pub fn recover_disputed_remainder(env: &Env, booking_id: u64) -> Result<i128, Va
 
     Ok(remainder)
 }
+
+/// Keep a booking's storage alive (callable by anyone).
+pub fn extend_booking_ttl(env: &Env, booking_id: u64) -> Result<(), VaultError> {
+    if !storage::extend_booking_ttl(env, booking_id) {
+        return Err(VaultError::BookingNotFound);
+    }
+    Ok(())
+}
+
+/// Maximum number of dispute approvers.
+const MAX_DISPUTE_APPROVERS: u32 = 10;
+/// Dispute proposals lapse if they are not fully approved within 7 days.
+const DISPUTE_PROPOSAL_TTL: u64 = 7 * 86_400;
+
+/// Configure the first M-of-N approver set for dispute actions (Admin role only).
+/// Afterwards `resolve_dispute` and `recover_disputed_remainder` are disabled, and the set
+/// itself can only be changed through an approved `SetApprovers` proposal.
+pub fn set_dispute_approvers(
+    env: &Env,
+    caller: &Address,
+    approvers: &Vec<Address>,
+    threshold: u32,
+) -> Result<(), VaultError> {
+    storage::get_admin(env).ok_or(VaultError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
+    if storage::get_dispute_threshold(env) > 0 {
+        return Err(VaultError::ApproversAlreadySet);
+    }
+    validate_approvers(approvers, threshold)?;
+    storage::set_dispute_approvers(env, approvers, threshold);
+    events::dispute_approvers_updated(env, approvers.len(), threshold);
+    Ok(())
+}
+
+/// Open a dispute proposal, counting the proposer's approval (Approver-only).
+/// Executes immediately if the threshold is one. Returns the proposal ID.
+pub fn propose_dispute_action(
+    env: &Env,
+    approver: &Address,
+    action: &DisputeAction,
+) -> Result<u64, VaultError> {
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+    require_dispute_approver(env, approver)?;
+    if let DisputeAction::SetApprovers(approvers, threshold) = action {
+        validate_approvers(approvers, *threshold)?;
+    }
+
+    let now = env.ledger().timestamp();
+    let proposal = DisputeProposal {
+        id: storage::get_next_dispute_proposal_id(env),
+        action: action.clone(),
+        proposer: approver.clone(),
+        approvals: vec![env, approver.clone()],
+        created_at: now,
+        expires_at: now + DISPUTE_PROPOSAL_TTL,
+        executed: false,
+    };
+    events::dispute_action_proposed(env, proposal.id, approver, proposal.expires_at);
+
+    let proposal_id = proposal.id;
+    execute_if_approved(env, proposal)?;
+    Ok(proposal_id)
+}
+
+/// Approve an open dispute proposal (Approver-only).
+/// Returns true if this approval met the threshold and the action was executed.
+pub fn approve_dispute_action(
+    env: &Env,
+    approver: &Address,
+    proposal_id: u64,
+) -> Result<bool, VaultError> {
+    if storage::is_paused(env) {
+        return Err(VaultError::ContractPaused);
+    }
+    require_dispute_approver(env, approver)?;
+
+    let mut proposal =
+        storage::get_dispute_proposal(env, proposal_id).ok_or(VaultError::ProposalNotFound)?;
+    if proposal.executed {
+        return Err(VaultError::ProposalExecuted);
+    }
+    if env.ledger().timestamp() >= proposal.expires_at {
+        return Err(VaultError::ProposalExpired);
+    }
+    if proposal.approvals.contains(approver) {
+        return Err(VaultError::AlreadyApproved);
+    }
+
+    proposal.approvals.push_back(approver.clone());
+    events::dispute_action_approved(env, proposal_id, approver, proposal.approvals.len());
+    execute_if_approved(env, proposal)
+}
+
+fn require_dispute_approver(env: &Env, approver: &Address) -> Result<(), VaultError> {
+    approver.require_auth();
+    if !storage::get_dispute_approvers(env).contains(approver) {
+        return Err(VaultError::NotApprover);
+    }
+    Ok(())
+}
+
+fn validate_approvers(approvers: &Vec<Address>, threshold: u32) -> Result<(), VaultError> {
+    if threshold == 0 || threshold > approvers.len() || approvers.len() > MAX_DISPUTE_APPROVERS {
+        return Err(VaultError::InvalidApprovers);
+    }
+    for (index, approver) in approvers.iter().enumerate() {
+        if approvers.first_index_of(&approver) != Some(index as u32) {
+            return Err(VaultError::InvalidApprovers);
+        }
+    }
+    Ok(())
+}
+
+/// Run the proposal's action if enough current approvers signed off, then persist it.
+/// Approvals from addresses removed from the set no longer count.
+fn execute_if_approved(env: &Env, mut proposal: DisputeProposal) -> Result<bool, VaultError> {
+    let approvers = storage::get_dispute_approvers(env);
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|approval| approvers.contains(approval))
+        .count() as u32;
+
+    if approvals < storage::get_dispute_threshold(env) {
+        storage::save_dispute_proposal(env, &proposal);
+        return Ok(false);
+    }
+
+    match proposal.action.clone() {
+        DisputeAction::Resolve(booking_id, user_refund, expert_pay) => {
+            apply_dispute_split(env, booking_id, user_refund, expert_pay)?;
+        }
+        DisputeAction::RecoverRemainder(booking_id) => {
+            apply_remainder_recovery(env, booking_id)?;
+        }
+        DisputeAction::SetApprovers(new_approvers, threshold) => {
+            storage::set_dispute_approvers(env, &new_approvers, threshold);
+            events::dispute_approvers_updated(env, new_approvers.len(), threshold);
+        }
+    }
+
+    proposal.executed = true;
+    storage::save_dispute_proposal(env, &proposal);
+    events::dispute_action_executed(env, proposal.id);
+    Ok(true)
+}
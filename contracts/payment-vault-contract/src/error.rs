@@ -1,4 +1,6 @@
+use access_control::AccessError;
 use soroban_sdk::contracterror;
+use upgrade_timelock::UpgradeError;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -18,4 +20,55 @@ pub enum VaultError {
     Overflow = 12,
     BookingNotDisputed = 13,
     RemainderAlreadyRecovered = 14,
+    SessionNotStarted = 15,
+    NothingToWithdraw = 16,
+    SessionKeyNotFound = 17,
+    SessionKeyExpired = 18,
+    SessionKeyLimitExceeded = 19,
+    FeeTooHigh = 20,
+    TreasuryNotSet = 21,
+    TokenNotAllowed = 22,
+    NoPendingAdmin = 23,
+    InvalidUpgradeDelay = 24,
+    NoPendingUpgrade = 25,
+    UpgradeNotReady = 26,
+    MultisigRequired = 27,
+    ApproversAlreadySet = 28,
+    InvalidApprovers = 29,
+    NotApprover = 30,
+    ProposalNotFound = 31,
+    ProposalExpired = 32,
+    ProposalExecuted = 33,
+    AlreadyApproved = 34,
+    InsufficientEscrow = 35,
+    InvalidDuration = 36,
+    ReceiptRequired = 37,
+    UnknownOracleKey = 38,
+    TooManyOracleKeys = 39,
+    ReceiptNonceUsed = 40,
+    InvalidOracleSet = 41,
+    NotOracle = 42,
+    DurationAlreadyReported = 43,
+    OracleQuorumRequired = 44,
+    DurationDisputed = 45,
+    SessionKeyAllowanceExpired = 46,
+}
+
+impl From<AccessError> for VaultError {
+    fn from(err: AccessError) -> Self {
+        match err {
+            AccessError::MissingRole => VaultError::NotAuthorized,
+            AccessError::NoPendingAdmin => VaultError::NoPendingAdmin,
+        }
+    }
+}
+
+impl From<UpgradeError> for VaultError {
+    fn from(err: UpgradeError) -> Self {
+        match err {
+            UpgradeError::InvalidDelay => VaultError::InvalidUpgradeDelay,
+            UpgradeError::NoPendingUpgrade => VaultError::NoPendingUpgrade,
+            UpgradeError::UpgradeNotReady => VaultError::UpgradeNotReady,
+        }
+    }
 }
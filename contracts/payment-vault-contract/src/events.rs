@@ -1,5 +1,5 @@
 #![allow(deprecated)]
-use soroban_sdk::{symbol_short, Address, Env};
+use soroban_sdk::{symbol_short, Address, BytesN, Env};
 
 /// Emitted when a new booking is created
 pub fn booking_created(
@@ -20,9 +20,9 @@ pub fn session_finalized(env: &Env, booking_id: u64, actual_duration: u64, total
     env.events().publish(topics, (actual_duration, total_cost));
 }
 
-pub fn session_reclaimed(env: &Env, booking_id: u64, amount: i128) {
+pub fn session_reclaimed(env: &Env, booking_id: u64, amount: i128, forfeited: i128) {
     let topics = (symbol_short!("reclaim"), booking_id);
-    env.events().publish(topics, amount);
+    env.events().publish(topics, (amount, forfeited));
 }
 
 /// Emitted when the contract is paused or unpaused
@@ -37,10 +37,37 @@ pub fn session_rejected(env: &Env, booking_id: u64, reason: &str) {
     env.events().publish(topics, reason);
 }
 
-/// Emitted when a user cancels a pending booking and receives a full refund
-pub fn booking_cancelled(env: &Env, booking_id: u64, amount: i128) {
+/// Emitted when an expert withdraws earnings streamed from a live session
+pub fn session_withdrawn(env: &Env, booking_id: u64, amount: i128, total_withdrawn: i128) {
+    let topics = (symbol_short!("withdraw"), booking_id);
+    env.events().publish(topics, (amount, total_withdrawn));
+}
+
+/// Emitted when a user cancels a pending booking; `amount` is the refund after the
+/// expert's cancellation policy and `forfeited` the share paid to the expert
+pub fn booking_cancelled(env: &Env, booking_id: u64, amount: i128, forfeited: i128) {
     let topics = (symbol_short!("cancel"), booking_id);
-    env.events().publish(topics, amount);
+    env.events().publish(topics, (amount, forfeited));
+}
+
+/// Emitted when a user ends a started session early; the expert is paid `expert_pay`
+/// for the elapsed time and the user gets `refund` back
+pub fn session_ended_by_user(env: &Env, booking_id: u64, expert_pay: i128, refund: i128) {
+    let topics = (symbol_short!("end_user"), booking_id);
+    env.events().publish(topics, (expert_pay, refund));
+}
+
+/// Emitted when an expert ends a started session early; `reason` is an optional
+/// app-defined code explaining why
+pub fn session_ended_by_expert(
+    env: &Env,
+    booking_id: u64,
+    expert_pay: i128,
+    refund: i128,
+    reason: Option<u32>,
+) {
+    let topics = (symbol_short!("end_exp"), booking_id);
+    env.events().publish(topics, (expert_pay, refund, reason));
 }
 
 /// Emitted when the Oracle marks a session as active/started
@@ -49,10 +76,16 @@ pub fn session_started(env: &Env, booking_id: u64, started_at: u64) {
     env.events().publish(topics, started_at);
 }
 
-/// Emitted when an expert updates their rate
-pub fn expert_rate_updated(env: &Env, expert: &Address, rate: i128) {
+/// Emitted when an expert updates their rate for a payment token
+pub fn expert_rate_updated(env: &Env, expert: &Address, token: &Address, rate: i128) {
     let topics = (symbol_short!("rate_upd"), expert.clone());
-    env.events().publish(topics, rate);
+    env.events().publish(topics, (token.clone(), rate));
+}
+
+/// Emitted when a payment token is added to or removed from the allowlist
+pub fn payment_token_updated(env: &Env, token: &Address, allowed: bool) {
+    let topics = (symbol_short!("token_upd"), token.clone());
+    env.events().publish(topics, allowed);
 }
 
 /// Emitted when admin is transferred to a new address
@@ -62,6 +95,32 @@ pub fn admin_transferred(env: &Env, old_admin: &Address, new_admin: &Address) {
         .publish(topics, (old_admin.clone(), new_admin.clone()));
 }
 
+/// Emitted when the admin proposes a successor, who must accept to take over.
+pub fn admin_proposed(env: &Env, admin: &Address, pending_admin: &Address) {
+    let topics = (symbol_short!("adm_prop"),);
+    env.events()
+        .publish(topics, (admin.clone(), pending_admin.clone()));
+}
+
+/// Emitted when the admin withdraws a pending admin proposal.
+pub fn admin_transfer_cancelled(env: &Env, admin: &Address, pending_admin: &Address) {
+    let topics = (symbol_short!("adm_cncl"),);
+    env.events()
+        .publish(topics, (admin.clone(), pending_admin.clone()));
+}
+
+/// Emitted when the calendar address used for cancellation policies is updated
+pub fn calendar_updated(env: &Env, calendar: &Address) {
+    let topics = (symbol_short!("cal_upd"),);
+    env.events().publish(topics, calendar.clone());
+}
+
+/// Emitted when the reputation contract notified of session outcomes is updated
+pub fn reputation_updated(env: &Env, reputation: &Address) {
+    let topics = (symbol_short!("rep_upd"),);
+    env.events().publish(topics, reputation.clone());
+}
+
 /// Emitted when the oracle address is updated
 pub fn oracle_updated(env: &Env, old_oracle: &Address, new_oracle: &Address) {
     let topics = (symbol_short!("orc_upd"),);
@@ -76,6 +135,30 @@ pub fn session_topped_up(env: &Env, booking_id: u64, additional_duration: u64, e
         .publish(topics, (additional_duration, extra_cost));
 }
 
+/// Emitted when a user delegates a session key to a booking
+pub fn session_key_registered(env: &Env, booking_id: u64, key: &Address, spend_limit: i128) {
+    let topics = (symbol_short!("skey_reg"), booking_id);
+    env.events().publish(topics, (key.clone(), spend_limit));
+}
+
+/// Emitted when a user revokes the session key of a booking
+pub fn session_key_revoked(env: &Env, booking_id: u64, key: &Address) {
+    let topics = (symbol_short!("skey_rev"), booking_id);
+    env.events().publish(topics, key.clone());
+}
+
+/// Emitted when the user (or their session key) acknowledges a live session is streaming
+pub fn session_acknowledged(env: &Env, booking_id: u64, signer: &Address, timestamp: u64) {
+    let topics = (symbol_short!("ack"), booking_id);
+    env.events().publish(topics, (signer.clone(), timestamp));
+}
+
+/// Emitted when the Oracle attests that a started session is still live
+pub fn session_attested(env: &Env, booking_id: u64, timestamp: u64) {
+    let topics = (symbol_short!("attested"), booking_id);
+    env.events().publish(topics, timestamp);
+}
+
 /// Emitted when an admin resolves a dispute by splitting escrowed funds
 pub fn dispute_resolved(env: &Env, booking_id: u64, user_refund: i128, expert_pay: i128) {
     let topics = (symbol_short!("dispute"), booking_id);
@@ -87,3 +170,91 @@ pub fn disputed_remainder_recovered(env: &Env, booking_id: u64, amount: i128) {
     let topics = (symbol_short!("dsp_rcvr"), booking_id);
     env.events().publish(topics, amount);
 }
+
+/// Emitted when the dispute approver set or threshold changes
+pub fn dispute_approvers_updated(env: &Env, approver_count: u32, threshold: u32) {
+    let topics = (symbol_short!("dsp_cfg"),);
+    env.events().publish(topics, (approver_count, threshold));
+}
+
+/// Emitted when an approver proposes a dispute action
+pub fn dispute_action_proposed(env: &Env, proposal_id: u64, proposer: &Address, expires_at: u64) {
+    let topics = (symbol_short!("dsp_prop"), proposal_id);
+    env.events().publish(topics, (proposer.clone(), expires_at));
+}
+
+/// Emitted when an approver signs off on a dispute proposal
+pub fn dispute_action_approved(env: &Env, proposal_id: u64, approver: &Address, approvals: u32) {
+    let topics = (symbol_short!("dsp_appr"), proposal_id);
+    env.events().publish(topics, (approver.clone(), approvals));
+}
+
+/// Emitted when a dispute proposal reaches its threshold and runs
+pub fn dispute_action_executed(env: &Env, proposal_id: u64) {
+    let topics = (symbol_short!("dsp_exec"), proposal_id);
+    env.events().publish(topics, ());
+}
+
+/// Emitted when the platform fee or treasury address is updated
+pub fn fee_config_updated(env: &Env, fee_bps: u32, treasury: &Address) {
+    let topics = (symbol_short!("fee_cfg"),);
+    env.events().publish(topics, (fee_bps, treasury.clone()));
+}
+
+/// Emitted when a platform fee is deducted from an expert payout
+pub fn fee_collected(env: &Env, booking_id: u64, fee: i128) {
+    let topics = (symbol_short!("fee_coll"), booking_id);
+    env.events().publish(topics, fee);
+}
+
+/// Emitted when accrued platform fees are withdrawn to the treasury
+pub fn fees_withdrawn(env: &Env, treasury: &Address, token: &Address, amount: i128) {
+    let topics = (symbol_short!("fee_wdr"),);
+    env.events()
+        .publish(topics, (treasury.clone(), token.clone(), amount));
+}
+
+/// Emitted when funds above the vault's liabilities are swept out
+pub fn excess_swept(env: &Env, token: &Address, to: &Address, amount: i128) {
+    let topics = (symbol_short!("swept"),);
+    env.events()
+        .publish(topics, (token.clone(), to.clone(), amount));
+}
+
+/// Emitted when an ed25519 key is added to or removed from the receipt signers
+pub fn oracle_key_updated(env: &Env, public_key: &BytesN<32>, registered: bool) {
+    let topics = (symbol_short!("orc_key"),);
+    env.events()
+        .publish(topics, (public_key.clone(), registered));
+}
+
+/// Emitted when signed receipts become required (or optional) for finalization
+pub fn receipts_required_updated(env: &Env, required: bool) {
+    let topics = (symbol_short!("rcpt_req"),);
+    env.events().publish(topics, required);
+}
+
+/// Emitted when a session is finalized with a signed usage receipt
+pub fn receipt_accepted(env: &Env, booking_id: u64, public_key: &BytesN<32>, nonce: u64) {
+    let topics = (symbol_short!("receipt"), booking_id);
+    env.events().publish(topics, (public_key.clone(), nonce));
+}
+
+/// Emitted when the admin configures the oracle set used for quorum finalization
+pub fn oracle_set_updated(env: &Env, oracles: u32, quorum: u32, tolerance: u64) {
+    let topics = (symbol_short!("orc_set"),);
+    env.events().publish(topics, (oracles, quorum, tolerance));
+}
+
+/// Emitted when an oracle reports a session's duration
+pub fn duration_reported(env: &Env, booking_id: u64, oracle: &Address, duration: u64) {
+    let topics = (symbol_short!("dur_rep"), booking_id);
+    env.events().publish(topics, (oracle.clone(), duration));
+}
+
+/// Emitted when a quorum of duration reports spreads wider than the tolerance;
+/// the booking then has to be settled through dispute resolution
+pub fn duration_disputed(env: &Env, booking_id: u64, min_duration: u64, max_duration: u64) {
+    let topics = (symbol_short!("dur_disp"), booking_id);
+    env.events().publish(topics, (min_duration, max_duration));
+}
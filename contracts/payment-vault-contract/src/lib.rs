@@ -8,15 +8,20 @@ mod test;
 mod types;
 
 use crate::error::VaultError;
-use crate::types::BookingRecord;
-use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+use crate::types::{
+    BookingRecord, DisputeAction, DisputeProposal, DurationReport, SessionKey, TokenLiabilities,
+};
+use access_control::Role;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+use upgrade_timelock::PendingUpgrade;
 
 #[contract]
 pub struct PaymentVaultContract;
 
 #[contractimpl]
 impl PaymentVaultContract {
-    /// Initialize the vault with the Admin, the Payment Token, the Oracle (Backend), and the Identity Registry
+    /// Initialize the vault with the Admin, the default Payment Token, the Oracle (Backend), and the Identity Registry.
+    /// The default token is the first entry of the payment token allowlist.
     pub fn init(
         env: Env,
         admin: Address,
@@ -27,73 +32,440 @@ impl PaymentVaultContract {
         contract::initialize_vault(&env, &admin, &token, &oracle, &registry)
     }
 
-    /// Pause the contract (Admin-only)
+    /// Pause the contract (Pauser role only)
     /// Halts all state-changing operations in an emergency
-    pub fn pause(env: Env) -> Result<(), VaultError> {
-        contract::pause(&env)
+    pub fn pause(env: Env, caller: Address) -> Result<(), VaultError> {
+        contract::pause(&env, &caller)
     }
 
-    /// Unpause the contract (Admin-only)
+    /// Unpause the contract (Pauser role only)
     /// Resumes normal contract operations
-    pub fn unpause(env: Env) -> Result<(), VaultError> {
-        contract::unpause(&env)
+    pub fn unpause(env: Env, caller: Address) -> Result<(), VaultError> {
+        contract::unpause(&env, &caller)
     }
 
-    /// Transfer admin rights to a new address (Admin-only)
-    /// Old admin instantly loses all privileges
-    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), VaultError> {
-        contract::transfer_admin(&env, &new_admin)
+    /// Grant a role to an account (Admin role only)
+    /// Oracle and Treasurer are assigned through `set_oracle` and `set_fee_config`
+    pub fn grant_role(
+        env: Env,
+        caller: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), VaultError> {
+        contract::grant_role(&env, &caller, role, &account)
+    }
+
+    /// Revoke a role from an account (Admin role only)
+    pub fn revoke_role(
+        env: Env,
+        caller: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), VaultError> {
+        contract::revoke_role(&env, &caller, role, &account)
+    }
+
+    /// Grant the admin roles to the stored admin, and the Oracle role to the stored oracle,
+    /// after upgrading a contract deployed before role storage existed (Admin-only)
+    pub fn migrate_roles(env: Env) -> Result<(), VaultError> {
+        contract::migrate_roles(&env)
+    }
+
+    /// Check whether an account holds a role
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        access_control::has_role(&env, role, &account)
+    }
+
+    /// Announce a WASM upgrade (Upgrader role only)
+    /// It becomes executable after the upgrade delay, giving users time to exit
+    pub fn schedule_upgrade(
+        env: Env,
+        caller: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), VaultError> {
+        upgrade_timelock::schedule_as_upgrader(&env, &caller, new_wasm_hash)
+    }
+
+    /// Apply the scheduled upgrade once its delay has elapsed (Upgrader role only)
+    pub fn execute_upgrade(env: Env, caller: Address) -> Result<(), VaultError> {
+        upgrade_timelock::execute_as_upgrader(&env, &caller)
+    }
+
+    /// Cancel the scheduled upgrade (Upgrader role only)
+    pub fn cancel_upgrade(env: Env, caller: Address) -> Result<(), VaultError> {
+        upgrade_timelock::cancel_as_upgrader(&env, &caller)
+    }
+
+    /// Set the delay in seconds between scheduling and executing an upgrade (Admin role only)
+    pub fn set_upgrade_delay(env: Env, caller: Address, delay: u64) -> Result<(), VaultError> {
+        upgrade_timelock::set_delay_as_admin(&env, &caller, delay)
+    }
+
+    /// Get the scheduled upgrade, if any
+    pub fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade> {
+        upgrade_timelock::get_pending(&env)
+    }
+
+    /// Get the delay in seconds between scheduling and executing an upgrade
+    pub fn get_upgrade_delay(env: Env) -> u64 {
+        upgrade_timelock::get_delay(&env)
+    }
+
+    /// Propose a new admin (Admin-only)
+    /// The current admin keeps full control until the nominee calls `accept_admin`
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), VaultError> {
+        contract::propose_admin(&env, &new_admin)
+    }
+
+    /// Accept a pending admin proposal (pending admin only)
+    /// The old admin loses its privileges at this point
+    pub fn accept_admin(env: Env) -> Result<(), VaultError> {
+        contract::accept_admin(&env)
+    }
+
+    /// Cancel a pending admin proposal (Admin-only)
+    pub fn cancel_admin_transfer(env: Env) -> Result<(), VaultError> {
+        contract::cancel_admin_transfer(&env)
+    }
+
+    /// Get the proposed admin awaiting acceptance, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        access_control::get_pending_admin(&env)
+    }
+
+    /// Register an ed25519 key whose signed usage receipts can finalize sessions (Admin role only).
+    pub fn add_oracle_key(
+        env: Env,
+        caller: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), VaultError> {
+        contract::add_oracle_key(&env, &caller, &public_key)
+    }
+
+    /// Stop accepting usage receipts signed by `public_key` (Admin role only).
+    pub fn remove_oracle_key(
+        env: Env,
+        caller: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), VaultError> {
+        contract::remove_oracle_key(&env, &caller, &public_key)
+    }
+
+    /// Get the ed25519 public keys allowed to sign usage receipts.
+    pub fn get_oracle_keys(env: Env) -> Vec<BytesN<32>> {
+        storage::get_oracle_keys(&env)
+    }
+
+    /// Require signed usage receipts for finalization (Admin role only).
+    /// While enabled, `finalize_session` and `report_duration` are rejected and only
+    /// `finalize_with_receipt` settles. Unavailable while an oracle set is configured.
+    pub fn set_receipts_required(
+        env: Env,
+        caller: Address,
+        required: bool,
+    ) -> Result<(), VaultError> {
+        contract::set_receipts_required(&env, &caller, required)
+    }
+
+    /// Check whether sessions can only be finalized with a signed usage receipt.
+    pub fn are_receipts_required(env: Env) -> bool {
+        storage::are_receipts_required(&env)
+    }
+
+    /// Configure the oracle set that reports session durations (Admin role only).
+    /// Sessions finalize on the median once `quorum` oracles report within `tolerance` seconds
+    /// of each other; wider spreads are left to dispute resolution.
+    /// While a set is configured, the single Oracle can no longer call `finalize_session`.
+    /// Pass an empty set with a zero quorum to disable it again.
+    pub fn set_oracle_set(
+        env: Env,
+        caller: Address,
+        oracles: Vec<Address>,
+        quorum: u32,
+        tolerance: u64,
+    ) -> Result<(), VaultError> {
+        contract::set_oracle_set(&env, &caller, &oracles, quorum, tolerance)
+    }
+
+    /// Get the oracle set, its quorum and the duration tolerance in seconds.
+    /// A quorum of zero means no set is configured.
+    pub fn get_oracle_set(env: Env) -> (Vec<Address>, u32, u64) {
+        (
+            storage::get_oracle_set(&env),
+            storage::get_oracle_quorum(&env),
+            storage::get_duration_tolerance(&env),
+        )
+    }
+
+    /// Report a session's duration (Oracle set member only).
+    /// Returns true if this report completed the quorum and finalized the session.
+    pub fn report_duration(
+        env: Env,
+        oracle: Address,
+        booking_id: u64,
+        duration: u64,
+    ) -> Result<bool, VaultError> {
+        contract::report_duration(&env, &oracle, booking_id, duration)
+    }
+
+    /// Get the duration reports submitted so far for a booking.
+    pub fn get_duration_reports(env: Env, booking_id: u64) -> Vec<DurationReport> {
+        storage::get_duration_reports(&env, booking_id)
     }
 
-    /// Update the oracle address (Admin-only)
+    /// Check whether a booking's duration reports diverged and it awaits dispute resolution.
+    pub fn is_duration_disputed(env: Env, booking_id: u64) -> bool {
+        storage::is_duration_disputed(&env, booking_id)
+    }
+
+    /// Update the oracle address (Admin role only)
     /// Old oracle instantly loses authorization to finalize sessions
-    pub fn set_oracle(env: Env, new_oracle: Address) -> Result<(), VaultError> {
-        contract::set_oracle(&env, &new_oracle)
+    pub fn set_oracle(env: Env, caller: Address, new_oracle: Address) -> Result<(), VaultError> {
+        contract::set_oracle(&env, &caller, &new_oracle)
+    }
+
+    /// Set the calendar contract whose cancellation policies govern `cancel_booking`
+    /// (Admin role only).
+    /// Without a calendar, cancellations are always fully refunded.
+    pub fn set_calendar_address(
+        env: Env,
+        caller: Address,
+        calendar: Address,
+    ) -> Result<(), VaultError> {
+        contract::set_calendar_address(&env, &caller, &calendar)
+    }
+
+    /// Get the configured calendar contract, if any.
+    pub fn get_calendar_address(env: Env) -> Option<Address> {
+        storage::get_calendar_address(&env)
+    }
+
+    /// Set the reputation contract notified when bookings finish (Admin role only).
+    /// Completed, rejected, reclaimed and disputed bookings are reported to it.
+    pub fn set_reputation_address(
+        env: Env,
+        caller: Address,
+        reputation: Address,
+    ) -> Result<(), VaultError> {
+        contract::set_reputation_address(&env, &caller, &reputation)
+    }
+
+    /// Get the configured reputation contract, if any.
+    pub fn get_reputation_address(env: Env) -> Option<Address> {
+        storage::get_reputation_address(&env)
+    }
+
+    /// Configure the platform fee in basis points and the treasury address (Admin role only).
+    /// The fee is deducted from every expert payout and is capped at 10% (1_000 bps).
+    pub fn set_fee_config(
+        env: Env,
+        caller: Address,
+        fee_bps: u32,
+        treasury: Address,
+    ) -> Result<(), VaultError> {
+        contract::set_fee_config(&env, &caller, fee_bps, &treasury)
+    }
+
+    /// Withdraw all platform fees accrued in `token` to the treasury (Treasurer role only).
+    /// Returns the amount withdrawn.
+    pub fn withdraw_fees(env: Env, treasurer: Address, token: Address) -> Result<i128, VaultError> {
+        contract::withdraw_fees(&env, &treasurer, &token)
+    }
+
+    /// Get the platform fee in basis points and the treasury address, if configured.
+    pub fn get_fee_config(env: Env) -> (u32, Option<Address>) {
+        (storage::get_fee_bps(&env), storage::get_treasury(&env))
+    }
+
+    /// Get the platform fees collected in `token` but not yet withdrawn to the treasury.
+    pub fn get_accrued_fees(env: Env, token: Address) -> i128 {
+        storage::get_accrued_fees(&env, &token)
+    }
+
+    /// Compare what the vault owes in each payment token (locked escrow plus accrued fees)
+    /// with the balance it actually holds. A negative surplus means the vault is insolvent.
+    pub fn get_liabilities(env: Env) -> Vec<TokenLiabilities> {
+        contract::get_liabilities(&env)
+    }
+
+    /// Send `token` held above the vault's liabilities to `to` (Admin role only).
+    /// Recovers stray transfers without touching escrow or fees. Returns the amount swept.
+    pub fn sweep_excess(
+        env: Env,
+        caller: Address,
+        token: Address,
+        to: Address,
+    ) -> Result<i128, VaultError> {
+        contract::sweep_excess(&env, &caller, &token, &to)
+    }
+
+    /// Add a token to the payment token allowlist (Admin role only).
+    pub fn add_payment_token(env: Env, caller: Address, token: Address) -> Result<(), VaultError> {
+        contract::add_payment_token(&env, &caller, &token)
+    }
+
+    /// Remove a token from the payment token allowlist (Admin role only).
+    /// Existing bookings in that token still settle normally; only new bookings are blocked.
+    pub fn remove_payment_token(
+        env: Env,
+        caller: Address,
+        token: Address,
+    ) -> Result<(), VaultError> {
+        contract::remove_payment_token(&env, &caller, &token)
+    }
+
+    /// Get the tokens currently accepted for new bookings.
+    pub fn get_payment_tokens(env: Env) -> Vec<Address> {
+        let mut allowed = Vec::new(&env);
+        for token in storage::get_payment_tokens(&env) {
+            if storage::is_token_allowed(&env, &token) {
+                allowed.push_back(token);
+            }
+        }
+        allowed
     }
 
-    /// Set an expert's own rate per second.
-    /// `rate_per_second` MUST be expressed in atomic units of the payment token
+    /// Set an expert's own rate per second for a given payment token.
+    /// `rate_per_second` MUST be expressed in atomic units of that token
     /// (e.g., 1 XLM = 10_000_000 stroops; 1 18-decimal token = 10^18 base units).
-    pub fn set_my_rate(env: Env, expert: Address, rate_per_second: i128) -> Result<(), VaultError> {
-        contract::set_my_rate(&env, &expert, rate_per_second)
+    pub fn set_my_rate(
+        env: Env,
+        expert: Address,
+        token: Address,
+        rate_per_second: i128,
+    ) -> Result<(), VaultError> {
+        contract::set_my_rate(&env, &expert, &token, rate_per_second)
     }
 
-    /// Book a session with an expert.
+    /// Get an expert's rate per second for a given payment token, if set.
+    pub fn get_expert_rate(env: Env, expert: Address, token: Address) -> Option<i128> {
+        storage::get_expert_rate(&env, &expert, &token)
+    }
+
+    /// Book a session with an expert, paying in `token`.
     /// User deposits tokens upfront based on rate_per_second * max_duration.
     /// Both `rate_per_second` and the resulting `total_deposit` are denominated in
-    /// atomic units of the configured payment token to correctly handle any token precision.
+    /// atomic units of the chosen payment token to correctly handle any token precision.
     pub fn book_session(
         env: Env,
         user: Address,
         expert: Address,
+        token: Address,
         max_duration: u64,
     ) -> Result<u64, VaultError> {
-        contract::book_session(&env, &user, &expert, max_duration)
+        contract::book_session(&env, &user, &expert, &token, max_duration)
     }
 
     /// Add more time to a live (or pending) session without disconnecting.
     /// Deducts corresponding tokens from the user based on the expert's rate.
+    /// `caller` is either the booking's user or its registered session key.
     pub fn top_up_session(
         env: Env,
-        user: Address,
+        caller: Address,
         booking_id: u64,
         additional_duration: u64,
     ) -> Result<(), VaultError> {
-        contract::top_up_session(&env, &user, booking_id, additional_duration)
+        contract::top_up_session(&env, &caller, booking_id, additional_duration)
+    }
+
+    /// Delegate an ephemeral session key to a booking (User-only).
+    /// The key can top up the session and acknowledge streaming without a wallet popup,
+    /// pulling at most `spend_limit` tokens. It expires at `started_at + max_duration`.
+    /// The vault's token allowance covers the unspent limits of all the user's keys in that token.
+    pub fn register_session_key(
+        env: Env,
+        user: Address,
+        booking_id: u64,
+        session_key: Address,
+        spend_limit: i128,
+    ) -> Result<(), VaultError> {
+        contract::register_session_key(&env, &user, booking_id, &session_key, spend_limit)
+    }
+
+    /// Revoke the session key of a booking, releasing its unspent limit (User-only).
+    pub fn revoke_session_key(env: Env, user: Address, booking_id: u64) -> Result<(), VaultError> {
+        contract::revoke_session_key(&env, &user, booking_id)
+    }
+
+    /// Renew the vault's token allowance for the user's session key budget (User-only).
+    /// The approval lapses after ~30 days, after which session keys can't top up until renewed.
+    pub fn renew_session_key_allowance(
+        env: Env,
+        user: Address,
+        token: Address,
+    ) -> Result<(), VaultError> {
+        contract::renew_session_key_allowance(&env, &user, &token)
+    }
+
+    /// Acknowledge that a started session is still streaming (User or session key).
+    pub fn acknowledge_session(
+        env: Env,
+        caller: Address,
+        booking_id: u64,
+    ) -> Result<(), VaultError> {
+        contract::acknowledge_session(&env, &caller, booking_id)
     }
 
-    /// Finalize a session (Oracle-only).
+    /// Get the session key registered on a booking, if any (read-only).
+    pub fn get_session_key(env: Env, booking_id: u64) -> Option<SessionKey> {
+        storage::get_session_key(&env, booking_id)
+    }
+
+    /// Finalize a session (Oracle role only).
     /// Calculates payments based on actual duration and processes refunds.
+    /// `actual_duration` may not exceed `max_duration` nor the time elapsed since the
+    /// session was marked started; a session that never started can only settle at zero.
+    /// Amounts already streamed beyond the reported duration stand; only the rest is refunded.
     pub fn finalize_session(
+        env: Env,
+        oracle: Address,
+        booking_id: u64,
+        actual_duration: u64,
+    ) -> Result<(), VaultError> {
+        contract::finalize_session(&env, &oracle, booking_id, actual_duration)
+    }
+
+    /// Finalize a session from a usage receipt signed by a registered oracle key (anyone can call).
+    /// The signature covers `"skillsphere:usage-receipt:v1" || vault address XDR || booking_id ||
+    /// actual_duration || nonce`, numbers as big-endian u64s; each nonce is accepted once.
+    /// Duration bounds are the same as `finalize_session`.
+    /// Rejected while an oracle set is configured; its quorum decides instead.
+    pub fn finalize_with_receipt(
         env: Env,
         booking_id: u64,
         actual_duration: u64,
+        nonce: u64,
+        public_key: BytesN<32>,
+        signature: BytesN<64>,
     ) -> Result<(), VaultError> {
-        contract::finalize_session(&env, booking_id, actual_duration)
+        contract::finalize_with_receipt(
+            &env,
+            booking_id,
+            actual_duration,
+            nonce,
+            &public_key,
+            &signature,
+        )
+    }
+
+    /// Withdraw earnings streamed from a live session (Expert-only).
+    /// After the Oracle marks the session started, the expert can claim
+    /// `rate_per_second * (attested_at - started_at)` (capped at `total_deposit`) at any time,
+    /// where `attested_at` is the Oracle's last `attest_session` heartbeat.
+    /// `finalize_session` later settles only the remainder. Returns the amount paid out after fees.
+    pub fn withdraw_streamed(
+        env: Env,
+        expert: Address,
+        booking_id: u64,
+    ) -> Result<i128, VaultError> {
+        contract::withdraw_streamed(&env, &expert, booking_id)
     }
 
     /// Reclaim funds from a stale booking (User-only).
-    /// Users can reclaim their deposit if the booking has been pending for more than 24 hours.
+    /// Users can reclaim their deposit once the booking has been pending for more than 24 hours
+    /// past the end of its reserved calendar slot, or past its creation if it has no slot.
+    /// A reserved slot that never started is forfeited under the expert's cancellation policy.
+    /// The reclaim is reported to the reputation contract as a neutral outcome.
     pub fn reclaim_stale_session(
         env: Env,
         user: Address,
@@ -103,41 +475,125 @@ impl PaymentVaultContract {
     }
 
     /// Reject a pending session (Expert-only).
-    /// Experts can reject a pending booking, instantly refunding the user.
+    /// Experts can reject a pending booking before it starts, instantly refunding the user.
     pub fn reject_session(env: Env, expert: Address, booking_id: u64) -> Result<(), VaultError> {
         contract::reject_session(&env, &expert, booking_id)
     }
 
-    /// Mark a session as started (Oracle-only).
+    /// Mark a session as started (Oracle role only).
     /// Once called, the user can no longer cancel the booking.
-    pub fn mark_session_started(env: Env, booking_id: u64) -> Result<(), VaultError> {
-        contract::mark_session_started(&env, booking_id)
+    pub fn mark_session_started(
+        env: Env,
+        oracle: Address,
+        booking_id: u64,
+    ) -> Result<(), VaultError> {
+        contract::mark_session_started(&env, &oracle, booking_id)
+    }
+
+    /// Attest that a started session is still live (Oracle role only).
+    /// Moves the bound streaming withdrawals accrue to; finalization can't bill less time.
+    pub fn attest_session(env: Env, oracle: Address, booking_id: u64) -> Result<(), VaultError> {
+        contract::attest_session(&env, &oracle, booking_id)
+    }
+
+    /// End a started session early (User-only).
+    /// The expert is paid `rate_per_second * (now - started_at)` minus anything already streamed,
+    /// the user is refunded the rest immediately, and the booking becomes `EndedByUser`.
+    /// Reported to the reputation contract as a neutral `EndedByUser` outcome.
+    pub fn end_session_early(env: Env, user: Address, booking_id: u64) -> Result<(), VaultError> {
+        contract::end_session_early(&env, &user, booking_id)
+    }
+
+    /// End a started session early (Expert-only), e.g. for an emergency.
+    /// The expert is paid only for the elapsed time, the user is refunded the rest,
+    /// and the booking becomes `EndedByExpert`. `reason` is an optional code echoed in the event.
+    /// The early exit is reported to the reputation contract as a reliability mark.
+    pub fn expert_end_session(
+        env: Env,
+        expert: Address,
+        booking_id: u64,
+        reason: Option<u32>,
+    ) -> Result<(), VaultError> {
+        contract::expert_end_session(&env, &expert, booking_id, reason)
     }
 
-    /// Cancel a pending booking and receive a full refund (User-only).
+    /// Cancel a pending booking (User-only).
     /// Cancellation is only allowed if the Oracle has not yet marked the session as started.
+    /// The refund follows the expert's cancellation policy in the calendar contract;
+    /// the forfeited share is paid to the expert (minus the platform fee).
     /// `rate_per_second` and `total_deposit` must always be expressed in atomic units
     /// of the payment token (e.g., stroops for XLM, or 10^18 base units for 18-decimal tokens).
     pub fn cancel_booking(env: Env, user: Address, booking_id: u64) -> Result<(), VaultError> {
         contract::cancel_booking(&env, &user, booking_id)
     }
 
-    /// Resolve a dispute by forcefully splitting escrowed funds (Admin-only).
+    /// Resolve a dispute by forcefully splitting escrowed funds (Admin role only).
     /// Used when the Oracle crashes or a severe, unresolvable dispute occurs.
     /// `user_refund + expert_pay` must not exceed the booking's `total_deposit`.
+    /// Disabled once dispute approvers are configured; use `propose_dispute_action` instead.
     pub fn resolve_dispute(
         env: Env,
+        caller: Address,
         booking_id: u64,
         user_refund: i128,
         expert_pay: i128,
     ) -> Result<(), VaultError> {
-        contract::resolve_dispute(&env, booking_id, user_refund, expert_pay)
+        contract::resolve_dispute(&env, &caller, booking_id, user_refund, expert_pay)
     }
 
-    /// Recover any disputed remainder still locked in vault after dispute split (Admin-only).
+    /// Recover any disputed remainder still locked in vault after dispute split (Admin role only).
     /// Can be executed once per booking after status reaches DisputedAndResolved.
-    pub fn recover_disputed_remainder(env: Env, booking_id: u64) -> Result<i128, VaultError> {
-        contract::recover_disputed_remainder(&env, booking_id)
+    /// Disabled once dispute approvers are configured; use `propose_dispute_action` instead.
+    pub fn recover_disputed_remainder(
+        env: Env,
+        caller: Address,
+        booking_id: u64,
+    ) -> Result<i128, VaultError> {
+        contract::recover_disputed_remainder(&env, &caller, booking_id)
+    }
+
+    /// Configure the M-of-N approver set for dispute actions (Admin-only, once).
+    /// From then on disputes are settled through approver proposals instead of the admin key.
+    pub fn set_dispute_approvers(
+        env: Env,
+        caller: Address,
+        approvers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), VaultError> {
+        contract::set_dispute_approvers(&env, &caller, &approvers, threshold)
+    }
+
+    /// Get the dispute approvers and the number of approvals required (0 if unset).
+    pub fn get_dispute_approvers(env: Env) -> (Vec<Address>, u32) {
+        (
+            storage::get_dispute_approvers(&env),
+            storage::get_dispute_threshold(&env),
+        )
+    }
+
+    /// Propose a dispute split, remainder recovery or approver change (Approver-only).
+    /// The proposer's approval counts; proposals expire after 7 days. Returns the proposal ID.
+    pub fn propose_dispute_action(
+        env: Env,
+        approver: Address,
+        action: DisputeAction,
+    ) -> Result<u64, VaultError> {
+        contract::propose_dispute_action(&env, &approver, &action)
+    }
+
+    /// Approve a dispute proposal (Approver-only); it executes once the threshold is met.
+    /// Returns true if the action was executed by this approval.
+    pub fn approve_dispute_action(
+        env: Env,
+        approver: Address,
+        proposal_id: u64,
+    ) -> Result<bool, VaultError> {
+        contract::approve_dispute_action(&env, &approver, proposal_id)
+    }
+
+    /// Get a dispute proposal by ID.
+    pub fn get_dispute_proposal(env: Env, proposal_id: u64) -> Option<DisputeProposal> {
+        storage::get_dispute_proposal(&env, proposal_id)
     }
 
     /// Get a paginated list of booking IDs for a specific user.
@@ -171,4 +627,17 @@ impl PaymentVaultContract {
     pub fn get_booking(env: Env, booking_id: u64) -> Option<BookingRecord> {
         storage::get_booking(&env, booking_id)
     }
+
+    /// Get what the expert earned from a settled session, before the platform fee.
+    /// None until the session is finalized or ended early.
+    pub fn get_expert_payout(env: Env, booking_id: u64) -> Option<i128> {
+        storage::get_expert_payout(&env, booking_id)
+    }
+
+    /// Extend the storage lifetime of a booking by about a year, together with its session key,
+    /// the user's and expert's booking index entries and the expert's rate.
+    /// Anyone can call this, e.g. to keep a long-pending booking's escrow reachable.
+    pub fn extend_booking_ttl(env: Env, booking_id: u64) -> Result<(), VaultError> {
+        contract::extend_booking_ttl(&env, booking_id)
+    }
 }
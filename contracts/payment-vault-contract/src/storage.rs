@@ -1,13 +1,14 @@
-use crate::types::{BookingRecord, BookingStatus};
-use soroban_sdk::{contracttype, Address, Env};
+use crate::types::{BookingRecord, BookingStatus, DisputeProposal, DurationReport, SessionKey};
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, Val, Vec};
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Admin,
-    Token,
+    AllowedToken(Address), // Token Address -> bool, payment token allowlist
+    PaymentTokens,         // Vec<Address> of every token ever allowlisted
     Oracle,
-    RegistryAddress,        
+    RegistryAddress,
     Booking(u64),            // Booking ID -> BookingRecord
     BookingCounter,          // Counter for generating unique booking IDs
     UserBookings(Address),   // User Address -> Vec<u64> of booking IDs
@@ -18,9 +19,124 @@ pub enum DataKey {
     UserBooking(Address, u32), // (user, index) -> booking_id
     UserBookingCount(Address), // user -> total count (u32)
     // ── Indexed Expert Booking List ────────────────────────────────────────
-    ExpertBooking(Address, u32), // (expert, index) -> booking_id
-    ExpertBookingCount(Address), // expert -> total count (u32)
-    ExpertRate(Address),     // Expert Address -> rate per second (i128)
+    ExpertBooking(Address, u32),  // (expert, index) -> booking_id
+    ExpertBookingCount(Address),  // expert -> total count (u32)
+    ExpertRate(Address, Address), // (expert, token) -> rate per second (i128)
+    SessionKey(u64),              // Booking ID -> delegated SessionKey
+    FeeBps,                       // Platform fee in basis points (u32)
+    Treasury,                     // Address receiving platform fees
+    AccruedFees(Address),         // Token -> platform fees collected but not yet withdrawn (i128)
+    LockedEscrow(Address),        // Token -> escrow still owed on open bookings (i128)
+    CalendarAddress,              // Calendar contract supplying cancellation policies
+    ReputationAddress,            // Reputation contract notified of session outcomes
+    DisputeApprovers,             // Vec<Address> allowed to propose and approve dispute actions
+    DisputeThreshold,             // Approvals required to execute a dispute action (u32)
+    DisputeProposal(u64),         // Proposal ID -> DisputeProposal
+    DisputeProposalCounter,       // Counter for generating unique proposal IDs
+    OracleKeys,                   // Vec<BytesN<32>> of ed25519 keys allowed to sign usage receipts
+    ReceiptsRequired,             // bool, when set sessions can only be finalized with a receipt
+    ReceiptNonce(u64),            // Nonce -> bool, receipt nonces already consumed
+    OracleSet,                    // Vec<Address> of oracles reporting session durations
+    OracleQuorum,                 // Matching reports required to finalize a session (u32)
+    DurationTolerance,            // Max spread in seconds between reports before disputing (u64)
+    DurationReports(u64),         // Booking ID -> Vec<DurationReport>
+    DurationDisputed(u64),        // Booking ID -> bool, reports diverged beyond the tolerance
+    SessionAttestedAt(u64),       // Booking ID -> last time the Oracle attested the session live
+    ExpertPayout(u64), // Booking ID -> expert's gross earnings once the session settles (i128)
+    // ── Session Key Allowance ──────────────────────────────────────────────
+    SessionKeyBudget(Address, Address), // (user, token) -> unspent session key limits (i128)
+}
+
+// --- TTL (Time To Live) ---
+// Ledgers close roughly every 5 seconds, so one year is ~6,300,000 ledgers.
+// Every write pushes the entry, and the contract instance, out to a year whenever
+// less than ~2 months remain. Escrow must stay reachable for as long as funds are locked.
+const LEDGERS_THRESHOLD: u32 = 1_000_000; // ~2 months
+const LEDGERS_EXTEND_TO: u32 = 6_300_000; // ~1 year
+
+/// Keep the contract instance (config, counters, fee balances) alive for another year
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+fn set_instance<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().instance().set(key, value);
+    extend_instance_ttl(env);
+}
+
+fn set_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+    extend_instance_ttl(env);
+}
+
+/// Extend a persistent entry to a year if it exists
+fn extend_persistent_if_present(env: &Env, key: &DataKey) {
+    if env.storage().persistent().has(key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+    }
+}
+
+/// Find the slot of `booking_id` in an append-only booking index of `count` entries.
+/// Booking ids only grow, so every index is sorted and can be binary searched.
+fn find_booking_slot(
+    env: &Env,
+    count: u32,
+    slot_key: impl Fn(u32) -> DataKey,
+    booking_id: u64,
+) -> Option<u32> {
+    let (mut low, mut high) = (0u32, count);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let id: u64 = env.storage().persistent().get(&slot_key(mid))?;
+        match id.cmp(&booking_id) {
+            core::cmp::Ordering::Equal => return Some(mid),
+            core::cmp::Ordering::Less => low = mid + 1,
+            core::cmp::Ordering::Greater => high = mid,
+        }
+    }
+    None
+}
+
+/// Keep a booking alive for another year, along with everything needed to list and
+/// settle it: its session key, the user's and expert's index entries, the expert's
+/// rate and the user's session key budget in the booking's token.
+/// Returns false if the booking does not exist.
+pub fn extend_booking_ttl(env: &Env, booking_id: u64) -> bool {
+    let Some(booking) = get_booking(env, booking_id) else {
+        return false;
+    };
+    let (user, expert, token) = (&booking.user, &booking.expert, &booking.token);
+
+    extend_persistent_if_present(env, &DataKey::Booking(booking_id));
+    extend_persistent_if_present(env, &DataKey::SessionKey(booking_id));
+    extend_persistent_if_present(env, &DataKey::SessionAttestedAt(booking_id));
+    extend_persistent_if_present(env, &DataKey::ExpertPayout(booking_id));
+    extend_persistent_if_present(env, &DataKey::ExpertRate(expert.clone(), token.clone()));
+    extend_persistent_if_present(env, &DataKey::SessionKeyBudget(user.clone(), token.clone()));
+
+    let user_count = get_user_booking_count(env, user);
+    extend_persistent_if_present(env, &DataKey::UserBookingCount(user.clone()));
+    let user_slot = |i| DataKey::UserBooking(user.clone(), i);
+    if let Some(slot) = find_booking_slot(env, user_count, user_slot, booking_id) {
+        extend_persistent_if_present(env, &user_slot(slot));
+    }
+
+    let expert_count = get_expert_booking_count(env, expert);
+    extend_persistent_if_present(env, &DataKey::ExpertBookingCount(expert.clone()));
+    let expert_slot = |i| DataKey::ExpertBooking(expert.clone(), i);
+    if let Some(slot) = find_booking_slot(env, expert_count, expert_slot, booking_id) {
+        extend_persistent_if_present(env, &expert_slot(slot));
+    }
+
+    extend_instance_ttl(env);
+    true
 }
 
 // --- Admin ---
@@ -29,7 +145,7 @@ pub fn has_admin(env: &Env) -> bool {
 }
 
 pub fn set_admin(env: &Env, admin: &Address) {
-    env.storage().instance().set(&DataKey::Admin, admin);
+    set_instance(env, &DataKey::Admin, admin);
 }
 
 #[allow(dead_code)]
@@ -37,36 +153,227 @@ pub fn get_admin(env: &Env) -> Option<Address> {
     env.storage().instance().get(&DataKey::Admin)
 }
 
-// --- Token (USDC/XLM) ---
-pub fn set_token(env: &Env, token: &Address) {
-    env.storage().instance().set(&DataKey::Token, token);
+// --- Payment Tokens (USDC/XLM/...) ---
+pub fn set_token_allowed(env: &Env, token: &Address, allowed: bool) {
+    set_instance(env, &DataKey::AllowedToken(token.clone()), &allowed);
+
+    let mut tokens = get_payment_tokens(env);
+    if !tokens.contains(token) {
+        tokens.push_back(token.clone());
+        set_instance(env, &DataKey::PaymentTokens, &tokens);
+    }
+}
+
+pub fn is_token_allowed(env: &Env, token: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::AllowedToken(token.clone()))
+        .unwrap_or(false)
 }
 
-pub fn get_token(env: &Env) -> Address {
-    env.storage().instance().get(&DataKey::Token).unwrap()
+/// Every token that has ever been allowlisted, including ones since removed.
+pub fn get_payment_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PaymentTokens)
+        .unwrap_or(Vec::new(env))
 }
 
 // --- Oracle (Backend) ---
 pub fn set_oracle(env: &Env, oracle: &Address) {
-    env.storage().instance().set(&DataKey::Oracle, oracle);
+    set_instance(env, &DataKey::Oracle, oracle);
 }
 
 pub fn get_oracle(env: &Env) -> Address {
     env.storage().instance().get(&DataKey::Oracle).unwrap()
 }
 
+// --- Oracle Receipt Keys ---
+pub fn get_oracle_keys(env: &Env) -> Vec<BytesN<32>> {
+    env.storage()
+        .instance()
+        .get(&DataKey::OracleKeys)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_oracle_keys(env: &Env, keys: &Vec<BytesN<32>>) {
+    set_instance(env, &DataKey::OracleKeys, keys);
+}
+
+pub fn are_receipts_required(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReceiptsRequired)
+        .unwrap_or(false)
+}
+
+pub fn set_receipts_required(env: &Env, required: bool) {
+    set_instance(env, &DataKey::ReceiptsRequired, &required);
+}
+
+pub fn is_receipt_nonce_used(env: &Env, nonce: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::ReceiptNonce(nonce))
+}
+
+pub fn mark_receipt_nonce_used(env: &Env, nonce: u64) {
+    set_persistent(env, &DataKey::ReceiptNonce(nonce), &true);
+}
+
+// --- Oracle Quorum ---
+pub fn set_oracle_set(env: &Env, oracles: &Vec<Address>, quorum: u32, tolerance: u64) {
+    set_instance(env, &DataKey::OracleSet, oracles);
+    set_instance(env, &DataKey::OracleQuorum, &quorum);
+    set_instance(env, &DataKey::DurationTolerance, &tolerance);
+}
+
+pub fn get_oracle_set(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::OracleSet)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Zero means no oracle set is configured and the single Oracle finalizes alone
+pub fn get_oracle_quorum(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::OracleQuorum)
+        .unwrap_or(0)
+}
+
+pub fn get_duration_tolerance(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DurationTolerance)
+        .unwrap_or(0)
+}
+
+pub fn get_duration_reports(env: &Env, booking_id: u64) -> Vec<DurationReport> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DurationReports(booking_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_duration_reports(env: &Env, booking_id: u64, reports: &Vec<DurationReport>) {
+    set_persistent(env, &DataKey::DurationReports(booking_id), reports);
+}
+
+pub fn is_duration_disputed(env: &Env, booking_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::DurationDisputed(booking_id))
+}
+
+pub fn set_duration_disputed(env: &Env, booking_id: u64) {
+    set_persistent(env, &DataKey::DurationDisputed(booking_id), &true);
+}
+
+pub fn get_session_attested_at(env: &Env, booking_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SessionAttestedAt(booking_id))
+}
+
+pub fn set_session_attested_at(env: &Env, booking_id: u64, timestamp: u64) {
+    set_persistent(env, &DataKey::SessionAttestedAt(booking_id), &timestamp);
+}
+
+pub fn get_expert_payout(env: &Env, booking_id: u64) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ExpertPayout(booking_id))
+}
+
+pub fn set_expert_payout(env: &Env, booking_id: u64, amount: i128) {
+    set_persistent(env, &DataKey::ExpertPayout(booking_id), &amount);
+}
+
+/// Drop a booking's duration reports, dispute flag and attestation once it leaves `Pending`
+pub fn clear_duration_state(env: &Env, booking_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DurationReports(booking_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DurationDisputed(booking_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::SessionAttestedAt(booking_id));
+    extend_instance_ttl(env);
+}
+
 // --- Registry (Identity) ---
 pub fn set_registry_address(env: &Env, registry: &Address) {
-    env.storage().instance().set(&DataKey::RegistryAddress, registry);
+    set_instance(env, &DataKey::RegistryAddress, registry);
 }
 
 pub fn get_registry_address(env: &Env) -> Option<Address> {
     env.storage().instance().get(&DataKey::RegistryAddress)
 }
 
+// --- Calendar ---
+pub fn set_calendar_address(env: &Env, calendar: &Address) {
+    set_instance(env, &DataKey::CalendarAddress, calendar);
+}
+
+pub fn get_calendar_address(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::CalendarAddress)
+}
+
+// --- Reputation ---
+pub fn set_reputation_address(env: &Env, reputation: &Address) {
+    set_instance(env, &DataKey::ReputationAddress, reputation);
+}
+
+pub fn get_reputation_address(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::ReputationAddress)
+}
+
+// --- Platform Fee & Treasury ---
+pub fn set_fee_bps(env: &Env, fee_bps: u32) {
+    set_instance(env, &DataKey::FeeBps, &fee_bps);
+}
+
+pub fn get_fee_bps(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::FeeBps).unwrap_or(0)
+}
+
+pub fn set_treasury(env: &Env, treasury: &Address) {
+    set_instance(env, &DataKey::Treasury, treasury);
+}
+
+pub fn get_treasury(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Treasury)
+}
+
+pub fn get_accrued_fees(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AccruedFees(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_accrued_fees(env: &Env, token: &Address, amount: i128) {
+    set_instance(env, &DataKey::AccruedFees(token.clone()), &amount);
+}
+
+pub fn get_locked_escrow(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::LockedEscrow(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_locked_escrow(env: &Env, token: &Address, amount: i128) {
+    set_instance(env, &DataKey::LockedEscrow(token.clone()), &amount);
+}
+
 // --- Pause (Circuit Breaker) ---
 pub fn set_paused(env: &Env, paused: bool) {
-    env.storage().instance().set(&DataKey::IsPaused, &paused);
+    set_instance(env, &DataKey::IsPaused, &paused);
 }
 
 pub fn is_paused(env: &Env) -> bool {
@@ -76,6 +383,48 @@ pub fn is_paused(env: &Env) -> bool {
         .unwrap_or(false)
 }
 
+// --- Dispute Multisig ---
+pub fn set_dispute_approvers(env: &Env, approvers: &Vec<Address>, threshold: u32) {
+    set_instance(env, &DataKey::DisputeApprovers, approvers);
+    set_instance(env, &DataKey::DisputeThreshold, &threshold);
+}
+
+pub fn get_dispute_approvers(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::DisputeApprovers)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Zero means no approver set is configured and the admin resolves disputes alone
+pub fn get_dispute_threshold(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DisputeThreshold)
+        .unwrap_or(0)
+}
+
+pub fn get_next_dispute_proposal_id(env: &Env) -> u64 {
+    let next: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::DisputeProposalCounter)
+        .unwrap_or(0u64)
+        + 1;
+    set_instance(env, &DataKey::DisputeProposalCounter, &next);
+    next
+}
+
+pub fn save_dispute_proposal(env: &Env, proposal: &DisputeProposal) {
+    set_persistent(env, &DataKey::DisputeProposal(proposal.id), proposal);
+}
+
+pub fn get_dispute_proposal(env: &Env, proposal_id: u64) -> Option<DisputeProposal> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DisputeProposal(proposal_id))
+}
+
 // --- Booking Counter ---
 pub fn get_next_booking_id(env: &Env) -> u64 {
     let current: u64 = env
@@ -84,17 +433,13 @@ pub fn get_next_booking_id(env: &Env) -> u64 {
         .get(&DataKey::BookingCounter)
         .unwrap_or(0);
     let next = current + 1;
-    env.storage()
-        .instance()
-        .set(&DataKey::BookingCounter, &next);
+    set_instance(env, &DataKey::BookingCounter, &next);
     next
 }
 
 // --- Bookings ---
 pub fn save_booking(env: &Env, booking: &BookingRecord) {
-    env.storage()
-        .persistent()
-        .set(&DataKey::Booking(booking.id), booking);
+    set_persistent(env, &DataKey::Booking(booking.id), booking);
 }
 
 pub fn get_booking(env: &Env, booking_id: u64) -> Option<BookingRecord> {
@@ -135,13 +480,9 @@ pub fn get_user_booking_count(env: &Env, user: &Address) -> u32 {
 pub fn add_booking_to_user_list(env: &Env, user: &Address, booking_id: u64) {
     let count = get_user_booking_count(env, user);
     // Store the new booking_id at slot `count` (0-indexed)
-    env.storage()
-        .persistent()
-        .set(&DataKey::UserBooking(user.clone(), count), &booking_id);
+    set_persistent(env, &DataKey::UserBooking(user.clone(), count), &booking_id);
     // Increment the counter
-    env.storage()
-        .persistent()
-        .set(&DataKey::UserBookingCount(user.clone()), &(count + 1));
+    set_persistent(env, &DataKey::UserBookingCount(user.clone()), &(count + 1));
 }
 
 /// Returns a paginated slice of booking IDs for a user.
@@ -184,12 +525,16 @@ pub fn get_expert_booking_count(env: &Env, expert: &Address) -> u32 {
 /// Appends a booking_id to the expert's list in O(1) — no Vec load/save.
 pub fn add_booking_to_expert_list(env: &Env, expert: &Address, booking_id: u64) {
     let count = get_expert_booking_count(env, expert);
-    env.storage()
-        .persistent()
-        .set(&DataKey::ExpertBooking(expert.clone(), count), &booking_id);
-    env.storage()
-        .persistent()
-        .set(&DataKey::ExpertBookingCount(expert.clone()), &(count + 1));
+    set_persistent(
+        env,
+        &DataKey::ExpertBooking(expert.clone(), count),
+        &booking_id,
+    );
+    set_persistent(
+        env,
+        &DataKey::ExpertBookingCount(expert.clone()),
+        &(count + 1),
+    );
 }
 
 /// Returns a paginated slice of booking IDs for an expert.
@@ -220,14 +565,49 @@ pub fn get_expert_bookings_paginated(
 }
 
 // --- Expert Rates ---
-pub fn set_expert_rate(env: &Env, expert: &Address, rate: i128) {
+pub fn set_expert_rate(env: &Env, expert: &Address, token: &Address, rate: i128) {
+    set_persistent(
+        env,
+        &DataKey::ExpertRate(expert.clone(), token.clone()),
+        &rate,
+    );
+}
+
+pub fn get_expert_rate(env: &Env, expert: &Address, token: &Address) -> Option<i128> {
     env.storage()
         .persistent()
-        .set(&DataKey::ExpertRate(expert.clone()), &rate);
+        .get(&DataKey::ExpertRate(expert.clone(), token.clone()))
+}
+
+// --- Session Keys ---
+pub fn set_session_key(env: &Env, booking_id: u64, session_key: &SessionKey) {
+    set_persistent(env, &DataKey::SessionKey(booking_id), session_key);
+}
+
+pub fn get_session_key(env: &Env, booking_id: u64) -> Option<SessionKey> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SessionKey(booking_id))
+}
+
+pub fn remove_session_key(env: &Env, booking_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::SessionKey(booking_id));
+    extend_instance_ttl(env);
+}
+
+pub fn set_session_key_budget(env: &Env, user: &Address, token: &Address, budget: i128) {
+    set_persistent(
+        env,
+        &DataKey::SessionKeyBudget(user.clone(), token.clone()),
+        &budget,
+    );
 }
 
-pub fn get_expert_rate(env: &Env, expert: &Address) -> Option<i128> {
+pub fn get_session_key_budget(env: &Env, user: &Address, token: &Address) -> i128 {
     env.storage()
         .persistent()
-        .get(&DataKey::ExpertRate(expert.clone()))
+        .get(&DataKey::SessionKeyBudget(user.clone(), token.clone()))
+        .unwrap_or(0)
 }
@@ -1,32 +1,93 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, Vec};
 
-/// Status of a booking in the payment vault
+pub use vault_types::{BookingRecord, BookingStatus};
+
+/// Ephemeral signer a user delegates to a booking so top-ups and streaming
+/// acknowledgements don't need a wallet signature each time.
+/// Expires on its own at `started_at + max_duration`.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SessionKey {
+    pub key: Address,      // Delegated signer address
+    pub spend_limit: i128, // Maximum amount the key may pull from the user's wallet
+    pub spent: i128,       // Amount already pulled through this key
+}
+
+/// Session duration reported by one member of the oracle set
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DurationReport {
+    pub oracle: Address,
+    pub duration: u64, // Billable seconds the oracle observed
+}
+
+/// Solvency snapshot of one payment token: what the vault owes versus what it holds
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenLiabilities {
+    pub token: Address,
+    pub locked_escrow: i128, // Escrow owed to users and experts of open bookings
+    pub accrued_fees: i128,  // Platform fees owed to the treasury
+    pub balance: i128,       // Vault's actual token balance
+    pub surplus: i128,       // balance - locked_escrow - accrued_fees; negative means insolvent
+}
+
+/// Terminal outcome of a booking reported to the ReputationScoringContract.
+/// Must match `SessionOutcome` in reputation-scoring-contract.
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u32)]
-pub enum BookingStatus {
-    Pending = 0,
-    Complete = 1,
-    Rejected = 2,
-    Reclaimed = 3,
-    DisputedAndResolved = 4,
-    Cancelled = 5,
+pub enum SessionOutcome {
+    Completed = 0,
+    Rejected = 1,
+    Reclaimed = 2,
+    DisputeWon = 3,
+    DisputeLost = 4,
+    EndedByExpert = 5,
+    EndedByUser = 6,
 }
 
-/// Record of a consultation booking with deposit locked
+/// Escrow operation that runs once enough dispute approvers sign off
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeAction {
+    Resolve(u64, i128, i128), // (booking_id, user_refund, expert_pay) split of a Pending booking
+    RecoverRemainder(u64),    // Send a resolved booking's leftover escrow to the admin
+    SetApprovers(Vec<Address>, u32), // Replace the approver set and threshold
+}
+
+/// Pending multisig proposal for a dispute action
 #[contracttype]
 #[derive(Clone, Debug)]
-pub struct BookingRecord {
-    pub id: u64,                           // Storage key identifier
-    pub user: Address,                     // User who created the booking
-    pub expert: Address,                   // Expert providing consultation
-    pub rate_per_second: i128, // Payment rate per second in atomic units of the payment token
-    pub max_duration: u64,     // Maximum booked duration in seconds
-    pub total_deposit: i128,   // Total deposit (rate_per_second * max_duration)
-    pub status: BookingStatus, // Current booking status
-    pub created_at: u64,       // Ledger timestamp when booking was created
-    pub started_at: Option<u64>, // Ledger timestamp when Oracle marked the session active; None means not yet started
-    pub dispute_user_refund: Option<i128>, // Admin-applied refund in dispute resolution, if resolved by dispute flow
-    pub dispute_expert_pay: Option<i128>, // Admin-applied expert payout in dispute resolution, if resolved by dispute flow
-    pub dispute_remainder_recovered: bool, // True once admin has recovered disputed remainder for this booking
+pub struct DisputeProposal {
+    pub id: u64,
+    pub action: DisputeAction,
+    pub proposer: Address,
+    pub approvals: Vec<Address>, // Approvers who signed off, proposer included
+    pub created_at: u64,
+    pub expires_at: u64, // Proposal can no longer be approved from this timestamp on
+    pub executed: bool,
+}
+
+// ── CalendarContract mirrors ─────────────────────────────────────────────────
+// Must match `CancellationTier` / `SlotReservation` in calendar-contract exactly,
+// since `get_reservation` results are decoded field by field.
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CalendarCancellationTier {
+    pub min_notice_seconds: u64,
+    pub refund_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CalendarReservation {
+    pub booking_id: u64,
+    pub user: Address,
+    pub expert: Address,
+    pub start_ts: u64,
+    pub end_ts: u64,
+    pub created_at: u64,
+    pub cancellation_policy: Vec<CalendarCancellationTier>,
 }
@@ -1,7 +1,33 @@
 use crate::error::ReputationError;
 use crate::events;
 use crate::storage;
-use soroban_sdk::{Address, BytesN, Env};
+use crate::types::{Reputation, ReputationState, Review, SessionOutcome};
+use access_control::Role;
+use soroban_sdk::{token, vec, Address, BytesN, Env, IntoVal, Symbol};
+use vault_types::{BookingRecord, BookingStatus};
+
+const MIN_RATING: u32 = 1;
+const MAX_RATING: u32 = 5;
+
+/// Fixed-point scale for scores, decay factors and effective review counts.
+const SCALE: i128 = 1_000_000;
+/// One penalty point removes 0.01 from the average rating.
+const PENALTY_POINT: i128 = SCALE / 100;
+/// A review's weight halves every 90 days.
+const HALF_LIFE_SECONDS: u64 = 90 * 86_400;
+/// ln(2) in fixed point.
+const LN_2: i128 = 693_147;
+/// Beyond this many half-lives the decay factor rounds to zero.
+const MAX_HALVINGS: u64 = 40;
+/// Effective review count at which confidence reaches 50%.
+const CONFIDENCE_PRIOR: i128 = 5 * SCALE;
+const MAX_CONFIDENCE_BPS: i128 = 10_000;
+/// Automatic penalties applied when the vault reports a bad outcome.
+const DISPUTE_LOST_PENALTY_POINTS: u64 = 50;
+/// Payouts are compared in millionths of a whole token, whatever the token's precision.
+const PAYOUT_DECIMALS: u32 = 6;
+/// A pre-upgrade score is carried over with the weight of a one-token session.
+const LEGACY_SCORE_WEIGHT: i128 = 1_000;
 
 pub fn initialize(
     env: &Env,
@@ -13,69 +39,380 @@ pub fn initialize(
     }
     storage::set_admin(env, admin);
     storage::set_vault_address(env, vault_address);
+    access_control::init_admin(env, admin);
     Ok(())
 }
 
-pub fn pause(env: &Env) -> Result<(), ReputationError> {
-    let admin = storage::get_admin(env).ok_or(ReputationError::NotInitialized)?;
-    admin.require_auth();
+pub fn pause(env: &Env, caller: &Address) -> Result<(), ReputationError> {
+    storage::get_admin(env).ok_or(ReputationError::NotInitialized)?;
+    access_control::require_role(env, Role::Pauser, caller)?;
     storage::set_paused(env, true);
     events::contract_paused(env, true);
     Ok(())
 }
 
-pub fn unpause(env: &Env) -> Result<(), ReputationError> {
-    let admin = storage::get_admin(env).ok_or(ReputationError::NotInitialized)?;
-    admin.require_auth();
+pub fn unpause(env: &Env, caller: &Address) -> Result<(), ReputationError> {
+    storage::get_admin(env).ok_or(ReputationError::NotInitialized)?;
+    access_control::require_role(env, Role::Pauser, caller)?;
     storage::set_paused(env, false);
     events::contract_paused(env, false);
     Ok(())
 }
 
-pub fn transfer_admin(env: &Env, new_admin: &Address) -> Result<(), ReputationError> {
+/// Grant `role` to `account` (Admin role only)
+pub fn grant_role(
+    env: &Env,
+    caller: &Address,
+    role: Role,
+    account: &Address,
+) -> Result<(), ReputationError> {
+    access_control::require_role(env, Role::Admin, caller)?;
+    access_control::grant_role(env, role, account, caller);
+    Ok(())
+}
+
+/// Revoke `role` from `account` (Admin role only).
+/// The primary admin keeps the Admin role until admin rights are transferred.
+pub fn revoke_role(
+    env: &Env,
+    caller: &Address,
+    role: Role,
+    account: &Address,
+) -> Result<(), ReputationError> {
+    access_control::require_role(env, Role::Admin, caller)?;
+    let admin = storage::get_admin(env).ok_or(ReputationError::NotInitialized)?;
+    if role == Role::Admin && *account == admin {
+        return Err(ReputationError::NotAuthorized);
+    }
+    access_control::revoke_role(env, role, account, caller);
+    Ok(())
+}
+
+/// Hand the stored admin its roles on a contract upgraded from before role storage (Admin-only).
+pub fn migrate_roles(env: &Env) -> Result<(), ReputationError> {
     let admin = storage::get_admin(env).ok_or(ReputationError::NotInitialized)?;
     admin.require_auth();
+    if !access_control::migrate_admin(env, &admin) {
+        return Err(ReputationError::AlreadyInitialized);
+    }
+    Ok(())
+}
+
+/// Nominate `new_admin` as the next admin (Admin-only).
+/// Nothing changes until the nominee calls `accept_admin`; a new proposal replaces any pending one.
+pub fn propose_admin(env: &Env, new_admin: &Address) -> Result<(), ReputationError> {
+    let admin = storage::get_admin(env).ok_or(ReputationError::NotInitialized)?;
     if storage::is_paused(env) {
         return Err(ReputationError::ContractPaused);
     }
-    storage::set_admin(env, new_admin);
-    events::admin_transferred(env, &admin, new_admin);
+    access_control::propose_admin(env, &admin, new_admin);
+    events::admin_proposed(env, &admin, new_admin);
     Ok(())
 }
 
-pub fn upgrade_contract(env: &Env, new_wasm_hash: BytesN<32>) -> Result<(), ReputationError> {
+/// Complete a pending admin transfer (pending admin only).
+pub fn accept_admin(env: &Env) -> Result<(), ReputationError> {
     let admin = storage::get_admin(env).ok_or(ReputationError::NotInitialized)?;
-    admin.require_auth();
-    env.deployer().update_current_contract_wasm(new_wasm_hash);
+    if storage::is_paused(env) {
+        return Err(ReputationError::ContractPaused);
+    }
+    let new_admin = access_control::accept_admin(env, &admin)?;
+    storage::set_admin(env, &new_admin);
+    events::admin_transferred(env, &admin, &new_admin);
+    Ok(())
+}
+
+/// Withdraw a pending admin proposal (Admin-only).
+pub fn cancel_admin_transfer(env: &Env) -> Result<(), ReputationError> {
+    let admin = storage::get_admin(env).ok_or(ReputationError::NotInitialized)?;
+    if storage::is_paused(env) {
+        return Err(ReputationError::ContractPaused);
+    }
+    let pending_admin = access_control::cancel_admin_transfer(env, &admin)?;
+    events::admin_transfer_cancelled(env, &admin, &pending_admin);
     Ok(())
 }
 
 pub fn penalize_expert(
     env: &Env,
+    caller: &Address,
     expert: &Address,
     penalty_points: u64,
 ) -> Result<(), ReputationError> {
     // Verify contract is initialized
-    let admin = storage::get_admin(env).ok_or(ReputationError::NotInitialized)?;
+    storage::get_admin(env).ok_or(ReputationError::NotInitialized)?;
+    access_control::require_role(env, Role::Admin, caller)?;
 
-    // Require auth from admin (vault authorization is handled through admin)
-    admin.require_auth();
+    let new_score = apply_penalty(env, expert, penalty_points)?;
+
+    // Emit event
+    events::expert_penalized(env, expert, penalty_points, new_score);
 
-    // Get current score
-    let current_score = storage::get_expert_score(env, expert);
+    Ok(())
+}
 
-    // Subtract penalty points, floor at 0 (prevent underflow)
-    let new_score = if current_score > penalty_points {
-        current_score - penalty_points
-    } else {
-        0
+/// Record a review for a completed vault booking (Reviewer-only).
+/// One review per booking; the rating is weighted by the square root of what the expert was paid.
+pub fn submit_review(
+    env: &Env,
+    user: &Address,
+    booking_id: u64,
+    rating: u32,
+    comment_hash: &BytesN<32>,
+) -> Result<(), ReputationError> {
+    if storage::is_paused(env) {
+        return Err(ReputationError::ContractPaused);
+    }
+
+    user.require_auth();
+
+    if !(MIN_RATING..=MAX_RATING).contains(&rating) {
+        return Err(ReputationError::InvalidRating);
+    }
+
+    if storage::has_review(env, booking_id) {
+        return Err(ReputationError::AlreadyReviewed);
+    }
+
+    // Confirm the booking via the PaymentVault
+    let vault = storage::get_vault_address(env).ok_or(ReputationError::NotInitialized)?;
+    let booking: Option<BookingRecord> = env.invoke_contract(
+        &vault,
+        &Symbol::new(env, "get_booking"),
+        vec![env, booking_id.into_val(env)],
+    );
+    let booking = booking.ok_or(ReputationError::BookingNotFound)?;
+
+    if booking.user != *user {
+        return Err(ReputationError::NotAuthorized);
+    }
+    // Sessions the user cut short still took place and can be reviewed
+    if !matches!(
+        booking.status,
+        BookingStatus::Complete | BookingStatus::EndedByUser | BookingStatus::EndedByExpert
+    ) {
+        return Err(ReputationError::BookingNotComplete);
+    }
+
+    let review = Review {
+        booking_id,
+        reviewer: user.clone(),
+        expert: booking.expert.clone(),
+        rating,
+        comment_hash: comment_hash.clone(),
+        created_at: env.ledger().timestamp(),
     };
+    storage::set_review(env, &review);
 
-    // Update score (do not increment review count)
-    storage::set_expert_score(env, expert, new_score);
+    // Sessions settled before the vault recorded payouts count with the minimum weight
+    let payout: Option<i128> = env.invoke_contract(
+        &vault,
+        &Symbol::new(env, "get_expert_payout"),
+        vec![env, booking_id.into_val(env)],
+    );
 
-    // Emit event
-    events::expert_penalized(env, expert, penalty_points, new_score);
+    // Decay existing aggregates to now, then fold in the new review
+    let weight = review_weight(env, &booking.token, payout.unwrap_or(0))?;
+    let mut state = decayed_state(env, &booking.expert)?;
+    state.weighted_ratings = weight
+        .checked_mul(rating as i128)
+        .and_then(|w| w.checked_add(state.weighted_ratings))
+        .ok_or(ReputationError::Overflow)?;
+    state.total_weight = state
+        .total_weight
+        .checked_add(weight)
+        .ok_or(ReputationError::Overflow)?;
+    state.effective_reviews = state
+        .effective_reviews
+        .checked_add(SCALE)
+        .ok_or(ReputationError::Overflow)?;
+    storage::set_expert_score(env, &booking.expert, &state);
+    let reviews = storage::get_expert_reviews(env, &booking.expert) + 1;
+    storage::set_expert_reviews(env, &booking.expert, reviews);
+
+    events::review_submitted(env, booking_id, &booking.expert, rating);
+
+    Ok(())
+}
+
+/// Record the terminal outcome of a vault booking (Vault-only).
+/// Lost disputes also penalize the expert.
+pub fn record_session_outcome(
+    env: &Env,
+    booking_id: u64,
+    expert: &Address,
+    outcome: SessionOutcome,
+) -> Result<(), ReputationError> {
+    if storage::is_paused(env) {
+        return Err(ReputationError::ContractPaused);
+    }
+
+    let vault = storage::get_vault_address(env).ok_or(ReputationError::NotInitialized)?;
+    vault.require_auth();
+
+    if storage::is_outcome_recorded(env, booking_id) {
+        return Err(ReputationError::OutcomeAlreadyRecorded);
+    }
+    storage::set_outcome_recorded(env, booking_id);
+
+    let mut stats = storage::get_session_stats(env, expert);
+    let penalty_points = match outcome {
+        SessionOutcome::Completed => {
+            stats.completed += 1;
+            0
+        }
+        SessionOutcome::Rejected => {
+            stats.rejected += 1;
+            0
+        }
+        SessionOutcome::Reclaimed => {
+            stats.reclaimed += 1;
+            0
+        }
+        SessionOutcome::DisputeWon => {
+            stats.disputes_won += 1;
+            0
+        }
+        SessionOutcome::DisputeLost => {
+            stats.disputes_lost += 1;
+            DISPUTE_LOST_PENALTY_POINTS
+        }
+        SessionOutcome::EndedByExpert => {
+            stats.ended_by_expert += 1;
+            0
+        }
+        SessionOutcome::EndedByUser => {
+            stats.ended_by_user += 1;
+            0
+        }
+    };
+    storage::set_session_stats(env, expert, &stats);
+
+    if penalty_points > 0 {
+        let new_score = apply_penalty(env, expert, penalty_points)?;
+        events::expert_penalized(env, expert, penalty_points, new_score);
+    }
+    events::outcome_recorded(env, booking_id, expert, outcome);
 
     Ok(())
 }
+
+/// Add a decaying penalty to the expert's state and return the resulting score.
+/// Penalties decay alongside reviews, so recent misconduct weighs the most.
+fn apply_penalty(env: &Env, expert: &Address, penalty_points: u64) -> Result<u64, ReputationError> {
+    let mut state = decayed_state(env, expert)?;
+    let deduction = (penalty_points as i128)
+        .checked_mul(PENALTY_POINT)
+        .ok_or(ReputationError::Overflow)?;
+    state.penalty = state
+        .penalty
+        .checked_add(deduction)
+        .ok_or(ReputationError::Overflow)?;
+    storage::set_expert_score(env, expert, &state);
+    Ok(score_of(&state))
+}
+
+/// Current reputation with all aggregates decayed to the ledger time.
+pub fn get_reputation(env: &Env, expert: &Address) -> Result<Reputation, ReputationError> {
+    let state = decayed_state(env, expert)?;
+    let confidence = state
+        .effective_reviews
+        .checked_mul(MAX_CONFIDENCE_BPS)
+        .ok_or(ReputationError::Overflow)?
+        / (state.effective_reviews + CONFIDENCE_PRIOR);
+
+    Ok(Reputation {
+        score: score_of(&state),
+        confidence: confidence as u32,
+        review_count: storage::get_expert_reviews(env, expert),
+    })
+}
+
+/// Weight of a review: the square root of the expert's payout, at least 1.
+/// The payout is first rescaled to `PAYOUT_DECIMALS`, so a 7-decimal and an 18-decimal token
+/// are compared in whole-token units rather than raw atomic units. Token prices are not
+/// known on-chain; the allowlist is expected to hold tokens of similar unit value, and the
+/// square root damps what gap remains (a 100× larger payout weighs 10× more).
+fn review_weight(env: &Env, token: &Address, payout: i128) -> Result<i128, ReputationError> {
+    let decimals = token::Client::new(env, token).decimals();
+    let normalized = if decimals >= PAYOUT_DECIMALS {
+        10i128
+            .checked_pow(decimals - PAYOUT_DECIMALS)
+            .map_or(0, |unit| payout / unit)
+    } else {
+        10i128
+            .checked_pow(PAYOUT_DECIMALS - decimals)
+            .and_then(|unit| payout.checked_mul(unit))
+            .ok_or(ReputationError::Overflow)?
+    };
+    Ok(normalized.max(0).isqrt().max(1))
+}
+
+/// Load the expert's aggregates and decay them from `updated_at` to now.
+fn decayed_state(env: &Env, expert: &Address) -> Result<ReputationState, ReputationError> {
+    let state = storage::get_expert_score(env, expert)
+        .or_else(|| {
+            storage::get_legacy_expert_score(env, expert).map(|score| legacy_state(env, score))
+        })
+        .unwrap_or_default();
+    let now = env.ledger().timestamp();
+    let factor = decay_factor(now.saturating_sub(state.updated_at));
+
+    Ok(ReputationState {
+        weighted_ratings: apply_decay(state.weighted_ratings, factor)?,
+        total_weight: apply_decay(state.total_weight, factor)?,
+        effective_reviews: apply_decay(state.effective_reviews, factor)?,
+        penalty: apply_decay(state.penalty, factor)?,
+        updated_at: now,
+    })
+}
+
+/// Fold a pre-upgrade u64 score into the aggregates as a single review with that score,
+/// so the next write persists it and it decays like any other review.
+fn legacy_state(env: &Env, score: u64) -> ReputationState {
+    ReputationState {
+        weighted_ratings: score as i128 * LEGACY_SCORE_WEIGHT / SCALE,
+        total_weight: LEGACY_SCORE_WEIGHT,
+        effective_reviews: SCALE,
+        penalty: 0,
+        updated_at: env.ledger().timestamp(),
+    }
+}
+
+/// Weighted average rating in fixed point, less penalties, floored at 0.
+fn score_of(state: &ReputationState) -> u64 {
+    if state.total_weight <= 0 {
+        return 0;
+    }
+    // weighted_ratings <= 5 × total_weight, so the quotient is at most 5 × SCALE
+    let average = (state.weighted_ratings / state.total_weight) * SCALE
+        + (state.weighted_ratings % state.total_weight) * SCALE / state.total_weight;
+    (average - state.penalty).max(0) as u64
+}
+
+fn apply_decay(value: i128, factor: i128) -> Result<i128, ReputationError> {
+    Ok(value.checked_mul(factor).ok_or(ReputationError::Overflow)? / SCALE)
+}
+
+/// 2^(-elapsed / HALF_LIFE) in fixed point.
+/// Whole half-lives are applied as shifts; the fractional part uses a Taylor series for e^(-x·ln2).
+fn decay_factor(elapsed: u64) -> i128 {
+    let halvings = elapsed / HALF_LIFE_SECONDS;
+    if halvings >= MAX_HALVINGS {
+        return 0;
+    }
+
+    let x = (elapsed % HALF_LIFE_SECONDS) as i128 * LN_2 / HALF_LIFE_SECONDS as i128;
+    let mut term = SCALE;
+    let mut factor = SCALE;
+    for k in 1..=6 {
+        term = term * x / SCALE / k;
+        if k % 2 == 1 {
+            factor -= term;
+        } else {
+            factor += term;
+        }
+    }
+
+    factor >> halvings
+}
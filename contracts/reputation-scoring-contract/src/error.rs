@@ -1,4 +1,6 @@
+use access_control::AccessError;
 use soroban_sdk::contracterror;
+use upgrade_timelock::UpgradeError;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -7,5 +9,34 @@ pub enum ReputationError {
     NotInitialized = 1,
     AlreadyInitialized = 2,
     ContractPaused = 3,
+    BookingNotFound = 4,
+    BookingNotComplete = 5,
+    AlreadyReviewed = 6,
+    InvalidRating = 7,
     NotAuthorized = 8,
+    Overflow = 9,
+    OutcomeAlreadyRecorded = 10,
+    NoPendingAdmin = 11,
+    InvalidUpgradeDelay = 12,
+    NoPendingUpgrade = 13,
+    UpgradeNotReady = 14,
+}
+
+impl From<AccessError> for ReputationError {
+    fn from(err: AccessError) -> Self {
+        match err {
+            AccessError::MissingRole => ReputationError::NotAuthorized,
+            AccessError::NoPendingAdmin => ReputationError::NoPendingAdmin,
+        }
+    }
+}
+
+impl From<UpgradeError> for ReputationError {
+    fn from(err: UpgradeError) -> Self {
+        match err {
+            UpgradeError::InvalidDelay => ReputationError::InvalidUpgradeDelay,
+            UpgradeError::NoPendingUpgrade => ReputationError::NoPendingUpgrade,
+            UpgradeError::UpgradeNotReady => ReputationError::UpgradeNotReady,
+        }
+    }
 }
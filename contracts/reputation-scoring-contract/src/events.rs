@@ -1,4 +1,5 @@
 #![allow(deprecated)]
+use crate::types::SessionOutcome;
 use soroban_sdk::{symbol_short, Address, Env};
 
 /// Emitted when the contract is paused or unpaused.
@@ -13,10 +14,34 @@ pub fn admin_transferred(env: &Env, old_admin: &Address, new_admin: &Address) {
     env.events()
         .publish(topics, (old_admin.clone(), new_admin.clone()));
 }
+
+/// Emitted when the admin proposes a successor, who must accept to take over.
+pub fn admin_proposed(env: &Env, admin: &Address, pending_admin: &Address) {
+    let topics = (symbol_short!("adm_prop"),);
+    env.events()
+        .publish(topics, (admin.clone(), pending_admin.clone()));
+}
+
+/// Emitted when the admin withdraws a pending admin proposal.
+pub fn admin_transfer_cancelled(env: &Env, admin: &Address, pending_admin: &Address) {
+    let topics = (symbol_short!("adm_cncl"),);
+    env.events()
+        .publish(topics, (admin.clone(), pending_admin.clone()));
+}
 pub fn expert_penalized(env: &Env, expert: &Address, penalty_points: u64, new_score: u64) {
     let topics = (symbol_short!("penalized"),);
-    env.events().publish(
-        topics,
-        (expert.clone(), penalty_points, new_score),
-    );
+    env.events()
+        .publish(topics, (expert.clone(), penalty_points, new_score));
+}
+
+/// Emitted when a user reviews a completed booking.
+pub fn review_submitted(env: &Env, booking_id: u64, expert: &Address, rating: u32) {
+    let topics = (symbol_short!("review"), booking_id);
+    env.events().publish(topics, (expert.clone(), rating));
+}
+
+/// Emitted when the vault reports a booking's terminal outcome.
+pub fn outcome_recorded(env: &Env, booking_id: u64, expert: &Address, outcome: SessionOutcome) {
+    let topics = (symbol_short!("outcome"), booking_id);
+    env.events().publish(topics, (expert.clone(), outcome));
 }
@@ -9,7 +9,10 @@ mod test;
 mod types;
 
 use crate::error::ReputationError;
+use crate::types::{Reputation, Review, SessionOutcome, SessionStats};
+use access_control::Role;
 use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use upgrade_timelock::PendingUpgrade;
 
 #[contract]
 pub struct ReputationScoringContract;
@@ -20,27 +23,154 @@ impl ReputationScoringContract {
         contract::initialize(&env, &admin, &vault_address)
     }
 
-    pub fn pause(env: Env) -> Result<(), ReputationError> {
-        contract::pause(&env)
+    /// Pause reviews and outcome recording (Pauser role only)
+    pub fn pause(env: Env, caller: Address) -> Result<(), ReputationError> {
+        contract::pause(&env, &caller)
     }
 
-    pub fn unpause(env: Env) -> Result<(), ReputationError> {
-        contract::unpause(&env)
+    /// Resume normal operation (Pauser role only)
+    pub fn unpause(env: Env, caller: Address) -> Result<(), ReputationError> {
+        contract::unpause(&env, &caller)
     }
 
-    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), ReputationError> {
-        contract::transfer_admin(&env, &new_admin)
+    /// Grant a role to an account (Admin role only)
+    pub fn grant_role(
+        env: Env,
+        caller: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), ReputationError> {
+        contract::grant_role(&env, &caller, role, &account)
+    }
+
+    /// Revoke a role from an account (Admin role only)
+    pub fn revoke_role(
+        env: Env,
+        caller: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), ReputationError> {
+        contract::revoke_role(&env, &caller, role, &account)
+    }
+
+    /// Grant the admin roles to the stored admin after upgrading a contract deployed
+    /// before role storage existed (Admin-only)
+    pub fn migrate_roles(env: Env) -> Result<(), ReputationError> {
+        contract::migrate_roles(&env)
+    }
+
+    /// Check whether an account holds a role
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        access_control::has_role(&env, role, &account)
+    }
+
+    /// Propose a new admin (Admin-only)
+    /// The current admin keeps full control until the nominee calls `accept_admin`
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), ReputationError> {
+        contract::propose_admin(&env, &new_admin)
     }
 
-    pub fn upgrade_contract(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), ReputationError> {
-        contract::upgrade_contract(&env, new_wasm_hash)
+    /// Accept a pending admin proposal (pending admin only)
+    /// The old admin loses its privileges at this point
+    pub fn accept_admin(env: Env) -> Result<(), ReputationError> {
+        contract::accept_admin(&env)
     }
 
+    /// Cancel a pending admin proposal (Admin-only)
+    pub fn cancel_admin_transfer(env: Env) -> Result<(), ReputationError> {
+        contract::cancel_admin_transfer(&env)
+    }
+
+    /// Get the proposed admin awaiting acceptance, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        access_control::get_pending_admin(&env)
+    }
+
+    /// Announce a WASM upgrade (Upgrader role only)
+    /// It becomes executable after the upgrade delay, giving users time to exit
+    pub fn schedule_upgrade(
+        env: Env,
+        caller: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), ReputationError> {
+        upgrade_timelock::schedule_as_upgrader(&env, &caller, new_wasm_hash)
+    }
+
+    /// Apply the scheduled upgrade once its delay has elapsed (Upgrader role only)
+    pub fn execute_upgrade(env: Env, caller: Address) -> Result<(), ReputationError> {
+        upgrade_timelock::execute_as_upgrader(&env, &caller)
+    }
+
+    /// Cancel the scheduled upgrade (Upgrader role only)
+    pub fn cancel_upgrade(env: Env, caller: Address) -> Result<(), ReputationError> {
+        upgrade_timelock::cancel_as_upgrader(&env, &caller)
+    }
+
+    /// Set the delay in seconds between scheduling and executing an upgrade (Admin role only)
+    pub fn set_upgrade_delay(env: Env, caller: Address, delay: u64) -> Result<(), ReputationError> {
+        upgrade_timelock::set_delay_as_admin(&env, &caller, delay)
+    }
+
+    /// Get the scheduled upgrade, if any
+    pub fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade> {
+        upgrade_timelock::get_pending(&env)
+    }
+
+    /// Get the delay in seconds between scheduling and executing an upgrade
+    pub fn get_upgrade_delay(env: Env) -> u64 {
+        upgrade_timelock::get_delay(&env)
+    }
+
+    /// Deduct penalty points from an expert's score (Admin role only)
     pub fn penalize_expert(
         env: Env,
+        caller: Address,
         expert: Address,
         penalty_points: u64,
     ) -> Result<(), ReputationError> {
-        contract::penalize_expert(&env, &expert, penalty_points)
+        contract::penalize_expert(&env, &caller, &expert, penalty_points)
+    }
+
+    /// Review a completed booking (Reviewer-only).
+    /// The booking must be `Complete` in the PaymentVault and belong to `user`;
+    /// each booking can be reviewed once. `rating` ranges from 1 to 5.
+    pub fn submit_review(
+        env: Env,
+        user: Address,
+        booking_id: u64,
+        rating: u32,
+        comment_hash: BytesN<32>,
+    ) -> Result<(), ReputationError> {
+        contract::submit_review(&env, &user, booking_id, rating, &comment_hash)
+    }
+
+    /// Get the review left for a booking, if any.
+    pub fn get_review(env: Env, booking_id: u64) -> Option<Review> {
+        storage::get_review(&env, booking_id)
+    }
+
+    /// Record a booking's terminal outcome (Vault-only).
+    /// Called by the configured PaymentVault when a session is finalized, rejected, ended early,
+    /// reclaimed as stale or resolved through a dispute. Each booking is recorded once.
+    pub fn record_session_outcome(
+        env: Env,
+        booking_id: u64,
+        expert: Address,
+        outcome: SessionOutcome,
+    ) -> Result<(), ReputationError> {
+        contract::record_session_outcome(&env, booking_id, &expert, outcome)
+    }
+
+    /// Get the expert's lifetime session history as reported by the vault.
+    pub fn get_session_stats(env: Env, expert: Address) -> SessionStats {
+        storage::get_session_stats(&env, &expert)
+    }
+
+    /// Get the expert's decay-weighted reputation.
+    /// `score` is the average rating scaled by 1_000_000 (e.g. 4_500_000 = 4.5 stars),
+    /// weighted by the square root of the expert's payout, with weights halving every 90 days.
+    /// `confidence` (bps) reflects how many recent reviews back the score.
+    pub fn get_reputation(env: Env, expert: Address) -> Result<Reputation, ReputationError> {
+        contract::get_reputation(&env, &expert)
     }
 }
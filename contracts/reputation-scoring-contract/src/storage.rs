@@ -1,4 +1,5 @@
-use soroban_sdk::{contracttype, Address, Env};
+use crate::types::{ReputationState, Review, SessionStats};
+use soroban_sdk::{contracttype, Address, Env, IntoVal, TryFromVal, Val};
 
 #[contracttype]
 #[derive(Clone)]
@@ -6,8 +7,36 @@ pub enum DataKey {
     Admin,
     VaultAddress,
     IsPaused,
-    ExpertScore(Address),
-    ExpertReviews(Address),
+    ExpertScore(Address), // Legacy instance entry holding a u64 score; folded in on next write
+    ExpertReviews(Address), // Expert -> lifetime review count (u64), legacy copy in instance
+    Review(u64),          // Booking ID -> Review
+    SessionStats(Address), // Expert -> SessionStats
+    OutcomeRecorded(u64), // Booking ID -> bool, set once the vault reports its outcome
+    ExpertReputation(Address), // Expert -> ReputationState
+}
+
+// --- TTL (Time To Live) ---
+// Reviews and scores are a long-lived record of an expert's history, so every write
+// or read pushes the entry out to a year once less than ~2 months remain.
+
+const LEDGERS_THRESHOLD: u32 = 1_000_000; // 2 months
+const LEDGERS_EXTEND_TO: u32 = 6_300_000; // 1 year
+
+fn set_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+}
+
+fn get_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, LEDGERS_THRESHOLD, LEDGERS_EXTEND_TO);
+    }
+    value
 }
 
 pub fn has_admin(env: &Env) -> bool {
@@ -37,32 +66,69 @@ pub fn set_paused(env: &Env, paused: bool) {
     env.storage().instance().set(&DataKey::IsPaused, &paused);
 }
 
-pub fn get_expert_score(env: &Env, expert: &Address) -> u64 {
+pub fn get_expert_score(env: &Env, expert: &Address) -> Option<ReputationState> {
+    get_persistent(env, &DataKey::ExpertReputation(expert.clone()))
+}
+
+/// Scores used to be a bare u64 in instance storage; those are read until the next write.
+pub fn get_legacy_expert_score(env: &Env, expert: &Address) -> Option<u64> {
     env.storage()
         .instance()
         .get(&DataKey::ExpertScore(expert.clone()))
-        .unwrap_or(0)
 }
 
-pub fn set_expert_score(env: &Env, expert: &Address, score: u64) {
+pub fn set_expert_score(env: &Env, expert: &Address, state: &ReputationState) {
+    set_persistent(env, &DataKey::ExpertReputation(expert.clone()), state);
+    // The written state already carries any legacy score
     env.storage()
         .instance()
-        .set(&DataKey::ExpertScore(expert.clone()), &score);
+        .remove(&DataKey::ExpertScore(expert.clone()));
 }
 
+/// Review counts used to live in instance storage; those are read until the next write moves them.
 pub fn get_expert_reviews(env: &Env, expert: &Address) -> u64 {
-    env.storage()
-        .instance()
-        .get(&DataKey::ExpertReviews(expert.clone()))
+    let key = DataKey::ExpertReviews(expert.clone());
+    get_persistent(env, &key)
+        .or_else(|| env.storage().instance().get(&key))
         .unwrap_or(0)
 }
 
 pub fn set_expert_reviews(env: &Env, expert: &Address, count: u64) {
-    env.storage()
-        .instance()
-        .set(&DataKey::ExpertReviews(expert.clone()), &count);
+    let key = DataKey::ExpertReviews(expert.clone());
+    set_persistent(env, &key, &count);
+    env.storage().instance().remove(&key);
 }
 
 pub fn get_vault_address(env: &Env) -> Option<Address> {
     env.storage().instance().get(&DataKey::VaultAddress)
 }
+
+pub fn has_review(env: &Env, booking_id: u64) -> bool {
+    env.storage().persistent().has(&DataKey::Review(booking_id))
+}
+
+pub fn get_review(env: &Env, booking_id: u64) -> Option<Review> {
+    get_persistent(env, &DataKey::Review(booking_id))
+}
+
+pub fn set_review(env: &Env, review: &Review) {
+    set_persistent(env, &DataKey::Review(review.booking_id), review);
+}
+
+pub fn get_session_stats(env: &Env, expert: &Address) -> SessionStats {
+    get_persistent(env, &DataKey::SessionStats(expert.clone())).unwrap_or_default()
+}
+
+pub fn set_session_stats(env: &Env, expert: &Address, stats: &SessionStats) {
+    set_persistent(env, &DataKey::SessionStats(expert.clone()), stats);
+}
+
+pub fn is_outcome_recorded(env: &Env, booking_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::OutcomeRecorded(booking_id))
+}
+
+pub fn set_outcome_recorded(env: &Env, booking_id: u64) {
+    set_persistent(env, &DataKey::OutcomeRecorded(booking_id), &true);
+}
@@ -1,15 +1,79 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use crate::error::ReputationError;
+use crate::types::SessionOutcome;
+use access_control::Role;
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    Address, BytesN, Env,
+};
+use vault_types::{BookingRecord, BookingStatus};
 
-fn setup() -> (Env, Address, Address, ReputationScoringContractClient<'static>) {
+// Mock PaymentVault serving bookings and payouts stored by the test
+mod mock_vault {
+    use soroban_sdk::{contract, contractimpl, Env};
+    use vault_types::BookingRecord;
+
+    #[contract]
+    pub struct MockVault;
+
+    #[contractimpl]
+    impl MockVault {
+        pub fn get_booking(env: Env, booking_id: u64) -> Option<BookingRecord> {
+            env.storage().instance().get(&booking_id)
+        }
+
+        pub fn set_booking(env: Env, booking: BookingRecord) {
+            env.storage().instance().set(&booking.id, &booking);
+        }
+
+        pub fn get_expert_payout(env: Env, booking_id: u64) -> Option<i128> {
+            env.storage().instance().get(&(booking_id, true))
+        }
+
+        pub fn set_expert_payout(env: Env, booking_id: u64, amount: i128) {
+            env.storage().instance().set(&(booking_id, true), &amount);
+        }
+    }
+}
+
+// Mock payment token reporting a configurable precision
+mod mock_token {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Env};
+
+    #[contract]
+    pub struct MockToken;
+
+    #[contractimpl]
+    impl MockToken {
+        pub fn decimals(env: Env) -> u32 {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("decimals"))
+                .unwrap_or(7)
+        }
+
+        pub fn set_decimals(env: Env, decimals: u32) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("decimals"), &decimals);
+        }
+    }
+}
+
+fn setup() -> (
+    Env,
+    Address,
+    Address,
+    ReputationScoringContractClient<'static>,
+) {
     let env = Env::default();
     env.mock_all_auths();
     let contract_id = env.register(ReputationScoringContract, ());
     let client = ReputationScoringContractClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
-    let vault = Address::generate(&env);
+    let vault = env.register(mock_vault::MockVault, ());
     (env, admin, vault, client)
 }
 
@@ -31,26 +95,143 @@ fn test_transfer_admin() {
     let (env, admin, vault, client) = setup();
     client.init(&admin, &vault);
     let new_admin = Address::generate(&env);
-    client.transfer_admin(&new_admin);
+    client.propose_admin(&new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    client.accept_admin();
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+fn test_cancel_admin_transfer() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let new_admin = Address::generate(&env);
+    client.propose_admin(&new_admin);
+    client.cancel_admin_transfer();
+
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(ReputationError::NoPendingAdmin))
+    );
+    assert!(client.has_role(&Role::Admin, &admin));
 }
 
 #[test]
 fn test_pause_blocks_transfer_admin() {
     let (env, admin, vault, client) = setup();
     client.init(&admin, &vault);
-    client.pause();
     let new_admin = Address::generate(&env);
-    assert!(client.try_transfer_admin(&new_admin).is_err());
+    client.propose_admin(&new_admin);
+    client.pause(&admin);
+    let paused = Err(Ok(ReputationError::ContractPaused));
+    assert_eq!(client.try_propose_admin(&new_admin), paused);
+    assert_eq!(client.try_accept_admin(), paused);
+    assert_eq!(client.try_cancel_admin_transfer(), paused);
+    assert_eq!(client.get_pending_admin(), Some(new_admin));
 }
 
 #[test]
 fn test_unpause_restores_transfer_admin() {
     let (env, admin, vault, client) = setup();
     client.init(&admin, &vault);
-    client.pause();
-    client.unpause();
+    client.pause(&admin);
+    client.unpause(&admin);
+    let new_admin = Address::generate(&env);
+    client.propose_admin(&new_admin);
+    client.accept_admin();
+}
+
+#[test]
+fn test_pause_requires_pauser_role() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let guardian = Address::generate(&env);
+    assert_eq!(
+        client.try_pause(&guardian),
+        Err(Ok(ReputationError::NotAuthorized))
+    );
+
+    client.grant_role(&admin, &Role::Pauser, &guardian);
+    client.pause(&guardian);
+    client.unpause(&guardian);
+
+    client.revoke_role(&admin, &Role::Pauser, &guardian);
+    assert!(!client.has_role(&Role::Pauser, &guardian));
+}
+
+#[test]
+fn test_upgrade_requires_upgrader_role() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let guardian = Address::generate(&env);
+    client.grant_role(&admin, &Role::Pauser, &guardian);
+
+    let hash = BytesN::from_array(&env, &[0u8; 32]);
+    let res = client.try_schedule_upgrade(&guardian, &hash);
+    assert_eq!(res, Err(Ok(ReputationError::NotAuthorized)));
+}
+
+#[test]
+fn test_schedule_upgrade_is_timelocked() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    env.ledger().set_timestamp(1_000);
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+    client.schedule_upgrade(&admin, &hash);
+
+    let pending = client.get_pending_upgrade().unwrap();
+    assert_eq!(pending.wasm_hash, hash);
+    assert_eq!(pending.executable_at, 1_000 + client.get_upgrade_delay());
+
+    env.ledger().set_timestamp(pending.executable_at - 1);
+    assert_eq!(
+        client.try_execute_upgrade(&admin),
+        Err(Ok(ReputationError::UpgradeNotReady))
+    );
+
+    client.cancel_upgrade(&admin);
+    assert_eq!(client.get_pending_upgrade(), None);
+    assert_eq!(
+        client.try_execute_upgrade(&admin),
+        Err(Ok(ReputationError::NoPendingUpgrade))
+    );
+}
+
+#[test]
+fn test_set_upgrade_delay() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let upgrader = Address::generate(&env);
+    client.grant_role(&admin, &Role::Upgrader, &upgrader);
+
+    client.set_upgrade_delay(&admin, &(7 * 86_400));
+    assert_eq!(client.get_upgrade_delay(), 7 * 86_400);
+    assert_eq!(
+        client.try_set_upgrade_delay(&admin, &60),
+        Err(Ok(ReputationError::InvalidUpgradeDelay))
+    );
+
+    // Upgraders cannot shorten their own notice period
+    assert_eq!(
+        client.try_set_upgrade_delay(&upgrader, &86_400),
+        Err(Ok(ReputationError::NotAuthorized))
+    );
+}
+
+#[test]
+fn test_transfer_admin_moves_roles() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
     let new_admin = Address::generate(&env);
-    client.transfer_admin(&new_admin);
+    client.propose_admin(&new_admin);
+    client.accept_admin();
+
+    assert!(!client.has_role(&Role::Admin, &admin));
+    assert!(client.has_role(&Role::Admin, &new_admin));
+    assert!(client.has_role(&Role::Pauser, &new_admin));
+    let res = client.try_grant_role(&admin, &Role::Pauser, &admin);
+    assert_eq!(res, Err(Ok(ReputationError::NotAuthorized)));
 }
 
 #[test]
@@ -60,19 +241,18 @@ fn test_penalize_expert_by_admin() {
     let expert = Address::generate(&env);
 
     // Admin should be able to penalize
-    client.penalize_expert(&expert, &50);
+    client.penalize_expert(&admin, &expert, &50);
 }
 
 #[test]
-fn test_penalize_expert_by_vault() {
+fn test_penalize_expert_requires_admin_role() {
     let (env, admin, vault, client) = setup();
     client.init(&admin, &vault);
-    let _expert = Address::generate(&env);
+    let expert = Address::generate(&env);
 
-    // Vault should be able to penalize
-    env.mock_all_auths();
-    // Simulate vault calling by mocking auth context
-    client.penalize_expert(&_expert, &50);
+    // The vault reports outcomes instead; it holds no role here
+    let res = client.try_penalize_expert(&vault, &expert, &50);
+    assert_eq!(res, Err(Ok(ReputationError::NotAuthorized)));
 }
 
 #[test]
@@ -87,15 +267,17 @@ fn test_penalize_expert_unauthorized() {
     let client_strict = ReputationScoringContractClient::new(&env_strict, &contract_id);
     let admin_strict = Address::generate(&env_strict);
     let vault_strict = Address::generate(&env_strict);
-    
+
     // Initialize with strict env
     client_strict.init(&admin_strict, &vault_strict);
-    
+
     let expert_strict = Address::generate(&env_strict);
     let _unauthorized = Address::generate(&env_strict);
-    
+
     // Unauthorized address should not be able to penalize (no auth mocking for this env)
-    assert!(client_strict.try_penalize_expert(&expert_strict, &50).is_err());
+    assert!(client_strict
+        .try_penalize_expert(&admin_strict, &expert_strict, &50)
+        .is_err());
 }
 
 #[test]
@@ -106,10 +288,10 @@ fn test_penalize_expert_score_underflow_protection() {
 
     // Penalize with more points than current score (default score is 0)
     // Should result in score of 0, not underflow
-    client.penalize_expert(&expert, &10);
-    
+    client.penalize_expert(&admin, &expert, &10);
+
     // Penalize again with 5 points, score should stay at 0
-    client.penalize_expert(&expert, &5);
+    client.penalize_expert(&admin, &expert, &5);
 }
 
 #[test]
@@ -119,8 +301,510 @@ fn test_penalize_expert_partial_deduction() {
     let expert = Address::generate(&env);
 
     // First penalize to set a score (100 - 30 = 70)
-    client.penalize_expert(&expert, &30);
-    
+    client.penalize_expert(&admin, &expert, &30);
+
     // Second penalize (70 - 20 = 50)
-    client.penalize_expert(&expert, &20);
-}
\ No newline at end of file
+    client.penalize_expert(&admin, &expert, &20);
+}
+// ==================== Review Tests ====================
+
+/// Record a settled booking on the mock vault
+fn vault_booking(
+    env: &Env,
+    vault: &Address,
+    id: u64,
+    user: &Address,
+    expert: &Address,
+    status: BookingStatus,
+) {
+    vault_booking_with_payout(env, vault, id, user, expert, status, 36_000);
+}
+
+fn vault_booking_with_payout(
+    env: &Env,
+    vault: &Address,
+    id: u64,
+    user: &Address,
+    expert: &Address,
+    status: BookingStatus,
+    payout: i128,
+) {
+    let token = env.register(mock_token::MockToken, ());
+    vault_booking_in_token(env, vault, id, user, expert, status, &token, payout);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn vault_booking_in_token(
+    env: &Env,
+    vault: &Address,
+    id: u64,
+    user: &Address,
+    expert: &Address,
+    status: BookingStatus,
+    token: &Address,
+    payout: i128,
+) {
+    let vault = mock_vault::MockVaultClient::new(env, vault);
+    vault.set_booking(&BookingRecord {
+        id,
+        user: user.clone(),
+        expert: expert.clone(),
+        token: token.clone(),
+        rate_per_second: 10,
+        max_duration: 3_600,
+        total_deposit: 36_000,
+        status,
+        created_at: 0,
+        started_at: None,
+        dispute_user_refund: None,
+        dispute_expert_pay: None,
+        dispute_remainder_recovered: false,
+        withdrawn_amount: 0,
+    });
+    vault.set_expert_payout(&id, &payout);
+}
+
+fn comment(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[7u8; 32])
+}
+
+#[test]
+fn test_submit_review() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Complete);
+    vault_booking(&env, &vault, 2, &user, &expert, BookingStatus::Complete);
+
+    client.submit_review(&user, &1, &5, &comment(&env));
+    client.submit_review(&user, &2, &3, &comment(&env));
+
+    let review = client.get_review(&1).unwrap();
+    assert_eq!(review.reviewer, user);
+    assert_eq!(review.expert, expert);
+    assert_eq!(review.rating, 5);
+    assert_eq!(review.comment_hash, comment(&env));
+
+    let reputation = client.get_reputation(&expert);
+    assert_eq!(reputation.score, 4_000_000);
+    assert_eq!(reputation.review_count, 2);
+}
+
+#[test]
+fn test_submit_review_once_per_booking() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Complete);
+
+    client.submit_review(&user, &1, &4, &comment(&env));
+    let res = client.try_submit_review(&user, &1, &5, &comment(&env));
+    assert_eq!(res, Err(Ok(ReputationError::AlreadyReviewed)));
+    assert_eq!(client.get_reputation(&expert).review_count, 1);
+}
+
+#[test]
+fn test_submit_review_requires_completed_booking() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Pending);
+    vault_booking(&env, &vault, 2, &user, &expert, BookingStatus::Cancelled);
+
+    let res = client.try_submit_review(&user, &1, &5, &comment(&env));
+    assert_eq!(res, Err(Ok(ReputationError::BookingNotComplete)));
+    let res = client.try_submit_review(&user, &2, &5, &comment(&env));
+    assert_eq!(res, Err(Ok(ReputationError::BookingNotComplete)));
+    let res = client.try_submit_review(&user, &3, &5, &comment(&env));
+    assert_eq!(res, Err(Ok(ReputationError::BookingNotFound)));
+}
+
+#[test]
+fn test_submit_review_for_sessions_ended_early() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::EndedByUser);
+    vault_booking(
+        &env,
+        &vault,
+        2,
+        &user,
+        &expert,
+        BookingStatus::EndedByExpert,
+    );
+
+    client.submit_review(&user, &1, &2, &comment(&env));
+    client.submit_review(&user, &2, &1, &comment(&env));
+    assert!(client.get_review(&1).is_some());
+    assert!(client.get_review(&2).is_some());
+}
+
+#[test]
+fn test_submit_review_rejects_other_users() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Complete);
+    let stranger = Address::generate(&env);
+
+    let res = client.try_submit_review(&stranger, &1, &5, &comment(&env));
+    assert_eq!(res, Err(Ok(ReputationError::NotAuthorized)));
+}
+
+#[test]
+fn test_submit_review_rejects_invalid_rating() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Complete);
+
+    let res = client.try_submit_review(&user, &1, &0, &comment(&env));
+    assert_eq!(res, Err(Ok(ReputationError::InvalidRating)));
+    let res = client.try_submit_review(&user, &1, &6, &comment(&env));
+    assert_eq!(res, Err(Ok(ReputationError::InvalidRating)));
+}
+
+#[test]
+fn test_pause_blocks_submit_review() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Complete);
+    client.pause(&admin);
+
+    let res = client.try_submit_review(&user, &1, &5, &comment(&env));
+    assert_eq!(res, Err(Ok(ReputationError::ContractPaused)));
+}
+
+// ==================== Weighted Reputation Tests ====================
+
+const HALF_LIFE: u64 = 90 * 86_400;
+
+#[test]
+fn test_reputation_weights_by_expert_payout() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let status = BookingStatus::Complete;
+    // Equal deposits; weights are square roots of the payout in millionths of a token,
+    // so 9_000 stroops weigh 30 and 1_000 stroops weigh 10
+    vault_booking_with_payout(&env, &vault, 1, &user, &expert, status, 9_000);
+    vault_booking_with_payout(&env, &vault, 2, &user, &expert, status, 1_000);
+
+    client.submit_review(&user, &1, &5, &comment(&env));
+    client.submit_review(&user, &2, &1, &comment(&env));
+
+    // (5 × 30 + 1 × 10) / 40 = 4.0
+    assert_eq!(client.get_reputation(&expert).score, 4_000_000);
+}
+
+#[test]
+fn test_reputation_compares_payouts_across_token_decimals() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let status = BookingStatus::Complete;
+    let stroop_token = env.register(mock_token::MockToken, ());
+    let wei_token = env.register(mock_token::MockToken, ());
+    mock_token::MockTokenClient::new(&env, &wei_token).set_decimals(&18);
+
+    // One whole token in each: 10^7 raw units weigh the same as 10^18
+    vault_booking_in_token(
+        &env,
+        &vault,
+        1,
+        &user,
+        &expert,
+        status,
+        &stroop_token,
+        10_000_000,
+    );
+    let one_wei_token = 1_000_000_000_000_000_000;
+    vault_booking_in_token(
+        &env,
+        &vault,
+        2,
+        &user,
+        &expert,
+        status,
+        &wei_token,
+        one_wei_token,
+    );
+    client.submit_review(&user, &1, &5, &comment(&env));
+    client.submit_review(&user, &2, &1, &comment(&env));
+
+    assert_eq!(client.get_reputation(&expert).score, 3_000_000);
+}
+
+#[test]
+fn test_reputation_handles_huge_payouts() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let status = BookingStatus::Complete;
+    // Far beyond any real balance; the aggregates must not overflow
+    let payout = i128::MAX / 4;
+    for id in 1..=3 {
+        vault_booking_with_payout(&env, &vault, id, &user, &expert, status, payout);
+        client.submit_review(&user, &id, &4, &comment(&env));
+    }
+
+    let reputation = client.get_reputation(&expert);
+    assert_eq!(reputation.score, 4_000_000);
+    assert_eq!(reputation.review_count, 3);
+}
+
+#[test]
+fn test_reputation_decays_old_reviews() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Complete);
+    vault_booking(&env, &vault, 2, &user, &expert, BookingStatus::Complete);
+
+    client.submit_review(&user, &1, &5, &comment(&env));
+
+    // One half-life later the old review counts half as much as the new one
+    env.ledger().set_timestamp(HALF_LIFE);
+    client.submit_review(&user, &2, &1, &comment(&env));
+
+    let reputation = client.get_reputation(&expert);
+    assert_eq!(reputation.score, 2_333_333);
+    assert_eq!(reputation.review_count, 2);
+}
+
+#[test]
+fn test_reputation_confidence_tracks_recent_volume() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    assert_eq!(client.get_reputation(&expert).confidence, 0);
+
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Complete);
+    client.submit_review(&user, &1, &4, &comment(&env));
+    assert_eq!(client.get_reputation(&expert).confidence, 1_666);
+
+    for id in 2..=5 {
+        vault_booking(&env, &vault, id, &user, &expert, BookingStatus::Complete);
+        client.submit_review(&user, &id, &4, &comment(&env));
+    }
+    assert_eq!(client.get_reputation(&expert).confidence, 5_000);
+
+    // Confidence fades without new reviews; the score itself does not
+    env.ledger().set_timestamp(HALF_LIFE);
+    let reputation = client.get_reputation(&expert);
+    assert_eq!(reputation.confidence, 3_333);
+    assert_eq!(reputation.score, 4_000_000);
+}
+
+#[test]
+fn test_penalty_reduces_and_decays_with_score() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Complete);
+    client.submit_review(&user, &1, &5, &comment(&env));
+
+    // 50 points = 0.5 stars
+    client.penalize_expert(&admin, &expert, &50);
+    assert_eq!(client.get_reputation(&expert).score, 4_500_000);
+
+    env.ledger().set_timestamp(HALF_LIFE);
+    assert_eq!(client.get_reputation(&expert).score, 4_750_000);
+
+    // Score never drops below zero
+    client.penalize_expert(&admin, &expert, &1_000);
+    assert_eq!(client.get_reputation(&expert).score, 0);
+}
+
+// ==================== Session Outcome Tests ====================
+
+#[test]
+fn test_record_session_outcomes() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let expert = Address::generate(&env);
+    client.record_session_outcome(&1, &expert, &SessionOutcome::Completed);
+    client.record_session_outcome(&2, &expert, &SessionOutcome::Completed);
+    client.record_session_outcome(&3, &expert, &SessionOutcome::Rejected);
+    client.record_session_outcome(&4, &expert, &SessionOutcome::DisputeWon);
+
+    let stats = client.get_session_stats(&expert);
+    assert_eq!(stats.completed, 2);
+    assert_eq!(stats.rejected, 1);
+    assert_eq!(stats.disputes_won, 1);
+    assert_eq!(stats.reclaimed, 0);
+    assert_eq!(stats.disputes_lost, 0);
+}
+
+#[test]
+fn test_only_lost_disputes_penalize_expert() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Complete);
+    client.submit_review(&user, &1, &5, &comment(&env));
+
+    // A reclaim blames nobody; a lost dispute costs 0.5 stars
+    client.record_session_outcome(&2, &expert, &SessionOutcome::Reclaimed);
+    assert_eq!(client.get_reputation(&expert).score, 5_000_000);
+    client.record_session_outcome(&3, &expert, &SessionOutcome::DisputeLost);
+    assert_eq!(client.get_reputation(&expert).score, 4_500_000);
+
+    let stats = client.get_session_stats(&expert);
+    assert_eq!(stats.reclaimed, 1);
+    assert_eq!(stats.disputes_lost, 1);
+}
+
+#[test]
+fn test_expert_early_end_counts_against_reliability() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Complete);
+    client.submit_review(&user, &1, &5, &comment(&env));
+
+    client.record_session_outcome(&2, &expert, &SessionOutcome::EndedByExpert);
+    client.record_session_outcome(&3, &expert, &SessionOutcome::EndedByExpert);
+
+    let stats = client.get_session_stats(&expert);
+    assert_eq!(stats.ended_by_expert, 2);
+    assert_eq!(stats.completed, 0);
+    // Emergencies are tracked, not penalized
+    assert_eq!(client.get_reputation(&expert).score, 5_000_000);
+}
+
+#[test]
+fn test_user_early_end_is_neutral() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Complete);
+    client.submit_review(&user, &1, &5, &comment(&env));
+
+    client.record_session_outcome(&2, &expert, &SessionOutcome::EndedByUser);
+
+    let stats = client.get_session_stats(&expert);
+    assert_eq!(stats.ended_by_user, 1);
+    assert_eq!(stats.ended_by_expert, 0);
+    assert_eq!(client.get_reputation(&expert).score, 5_000_000);
+}
+
+#[test]
+fn test_record_session_outcome_once_per_booking() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let expert = Address::generate(&env);
+    client.record_session_outcome(&1, &expert, &SessionOutcome::Completed);
+    let res = client.try_record_session_outcome(&1, &expert, &SessionOutcome::Reclaimed);
+    assert_eq!(res, Err(Ok(ReputationError::OutcomeAlreadyRecorded)));
+}
+
+#[test]
+#[should_panic]
+fn test_record_session_outcome_requires_vault() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let expert = Address::generate(&env);
+    env.mock_auths(&[]);
+    client.record_session_outcome(&1, &expert, &SessionOutcome::Reclaimed);
+}
+
+// ==================== Storage Tests ====================
+
+#[test]
+fn test_expert_aggregates_live_in_persistent_storage() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Complete);
+    client.submit_review(&user, &1, &5, &comment(&env));
+
+    env.as_contract(&client.address, || {
+        let keys = [
+            crate::storage::DataKey::ExpertReputation(expert.clone()),
+            crate::storage::DataKey::ExpertReviews(expert.clone()),
+            crate::storage::DataKey::Review(1),
+        ];
+        for key in keys.iter() {
+            assert!(env.storage().persistent().get_ttl(key) >= 1_000_000);
+        }
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&crate::storage::DataKey::ExpertReviews(expert.clone())));
+    });
+}
+
+#[test]
+fn test_legacy_instance_entries_are_migrated() {
+    let (env, admin, vault, client) = setup();
+    client.init(&admin, &vault);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let score_key = crate::storage::DataKey::ExpertScore(expert.clone());
+    let reviews_key = crate::storage::DataKey::ExpertReviews(expert.clone());
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&score_key, &4_200_000u64);
+        env.storage().instance().set(&reviews_key, &4u64);
+    });
+    let reputation = client.get_reputation(&expert);
+    assert_eq!(reputation.score, 4_200_000);
+    assert_eq!(reputation.review_count, 4);
+
+    // The legacy score weighs 1_000 against the new review's 60
+    vault_booking(&env, &vault, 1, &user, &expert, BookingStatus::Complete);
+    client.submit_review(&user, &1, &5, &comment(&env));
+
+    let reputation = client.get_reputation(&expert);
+    assert_eq!(reputation.score, 4_245_283);
+    assert_eq!(reputation.review_count, 5);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&score_key));
+        assert!(!env.storage().instance().has(&reviews_key));
+    });
+}
+
+#[test]
+fn test_migrate_roles_from_pre_rbac_state() {
+    let (env, admin, vault, client) = setup();
+    // A contract deployed before role storage only recorded these addresses
+    env.as_contract(&client.address, || {
+        let instance = env.storage().instance();
+        instance.set(&crate::storage::DataKey::Admin, &admin);
+        instance.set(&crate::storage::DataKey::VaultAddress, &vault);
+    });
+    let expert = Address::generate(&env);
+    assert_eq!(
+        client.try_penalize_expert(&admin, &expert, &10),
+        Err(Ok(ReputationError::NotAuthorized))
+    );
+
+    client.migrate_roles();
+    for role in access_control::ADMIN_ROLES {
+        assert!(client.has_role(&role, &admin));
+    }
+    client.penalize_expert(&admin, &expert, &10);
+    assert_eq!(
+        client.try_migrate_roles(),
+        Err(Ok(ReputationError::AlreadyInitialized))
+    );
+}
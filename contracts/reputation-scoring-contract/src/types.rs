@@ -0,0 +1,62 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+/// A user's review of a completed booking
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Review {
+    pub booking_id: u64,
+    pub reviewer: Address,
+    pub expert: Address,
+    pub rating: u32,              // 1..=5 stars
+    pub comment_hash: BytesN<32>, // Hash of the off-chain review text
+    pub created_at: u64,
+}
+
+/// Running, time-decayed rating aggregates for an expert.
+/// All sums are decayed to `updated_at`; fixed-point values use `SCALE` (1_000_000).
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReputationState {
+    pub weighted_ratings: i128,  // Σ rating × weight
+    pub total_weight: i128,      // Σ weight (square roots of expert payouts)
+    pub effective_reviews: i128, // Σ SCALE per review, fixed-point
+    pub penalty: i128,           // Score deducted by penalties, fixed-point
+    pub updated_at: u64,
+}
+
+/// Public view of an expert's reputation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reputation {
+    pub score: u64,        // Decay-weighted average rating × 1_000_000, minus penalties
+    pub confidence: u32,   // 0..=10_000 bps, grows with recent review volume
+    pub review_count: u64, // Lifetime number of reviews
+}
+
+/// Terminal outcome of a vault booking, reported by the PaymentVault.
+/// Mirrored by `SessionOutcome` in payment-vault-contract.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum SessionOutcome {
+    Completed = 0,     // Finalized by the Oracle
+    Rejected = 1,      // Declined by the expert
+    Reclaimed = 2,     // Never settled; the user reclaimed the stale escrow. Neutral for the expert
+    DisputeWon = 3,    // Dispute resolved at least half in the expert's favour
+    DisputeLost = 4,   // Dispute resolved mostly in the user's favour
+    EndedByExpert = 5, // Expert walked out of a started session
+    EndedByUser = 6,   // User cut a started session short; neutral for the expert
+}
+
+/// Lifetime session history for an expert
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SessionStats {
+    pub completed: u64,
+    pub rejected: u64,
+    pub reclaimed: u64, // Stale bookings the user reclaimed; nobody is blamed
+    pub disputes_won: u64,
+    pub disputes_lost: u64,
+    pub ended_by_expert: u64, // Started sessions the expert cut short; counts against reliability
+    pub ended_by_user: u64,   // Started sessions the user cut short
+}
@@ -0,0 +1,173 @@
+use crate::error::CoreError;
+use crate::events;
+use crate::storage;
+use crate::types::ContractAddresses;
+use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, Symbol};
+
+pub fn initialize(
+    env: &Env,
+    admin: &Address,
+    contracts: &ContractAddresses,
+) -> Result<(), CoreError> {
+    if storage::has_admin(env) {
+        return Err(CoreError::AlreadyInitialized);
+    }
+    storage::set_admin(env, admin);
+    storage::set_contracts(env, contracts);
+    Ok(())
+}
+
+pub fn pause(env: &Env) -> Result<(), CoreError> {
+    let admin = storage::get_admin(env).ok_or(CoreError::NotInitialized)?;
+    admin.require_auth();
+    storage::set_paused(env, true);
+    events::contract_paused(env, true);
+    Ok(())
+}
+
+pub fn unpause(env: &Env) -> Result<(), CoreError> {
+    let admin = storage::get_admin(env).ok_or(CoreError::NotInitialized)?;
+    admin.require_auth();
+    storage::set_paused(env, false);
+    events::contract_paused(env, false);
+    Ok(())
+}
+
+/// Nominate `new_admin` as the next admin (Admin-only).
+/// Nothing changes until the nominee calls `accept_admin`; a new proposal replaces any pending one.
+pub fn propose_admin(env: &Env, new_admin: &Address) -> Result<(), CoreError> {
+    let admin = storage::get_admin(env).ok_or(CoreError::NotInitialized)?;
+    if storage::is_paused(env) {
+        return Err(CoreError::ContractPaused);
+    }
+    access_control::propose_admin(env, &admin, new_admin);
+    events::admin_proposed(env, &admin, new_admin);
+    Ok(())
+}
+
+/// Complete a pending admin transfer (pending admin only).
+pub fn accept_admin(env: &Env) -> Result<(), CoreError> {
+    let admin = storage::get_admin(env).ok_or(CoreError::NotInitialized)?;
+    if storage::is_paused(env) {
+        return Err(CoreError::ContractPaused);
+    }
+    let new_admin =
+        access_control::accept_admin(env, &admin).map_err(|_| CoreError::NoPendingAdmin)?;
+    storage::set_admin(env, &new_admin);
+    events::admin_transferred(env, &admin, &new_admin);
+    Ok(())
+}
+
+/// Withdraw a pending admin proposal (Admin-only).
+pub fn cancel_admin_transfer(env: &Env) -> Result<(), CoreError> {
+    let admin = storage::get_admin(env).ok_or(CoreError::NotInitialized)?;
+    if storage::is_paused(env) {
+        return Err(CoreError::ContractPaused);
+    }
+    let pending_admin = access_control::cancel_admin_transfer(env, &admin)
+        .map_err(|_| CoreError::NoPendingAdmin)?;
+    events::admin_transfer_cancelled(env, &admin, &pending_admin);
+    Ok(())
+}
+
+pub fn set_contracts(env: &Env, contracts: &ContractAddresses) -> Result<(), CoreError> {
+    let admin = storage::get_admin(env).ok_or(CoreError::NotInitialized)?;
+    admin.require_auth();
+    storage::set_contracts(env, contracts);
+    events::contracts_updated(env, contracts);
+    Ok(())
+}
+
+/// Verify the expert, lock the deposit in the vault and reserve the calendar slot.
+/// Each cross-contract call panics on failure, so the whole transaction rolls back
+/// and no half-booked state is ever left behind.
+pub fn book_slot_and_pay(
+    env: &Env,
+    user: &Address,
+    expert: &Address,
+    token: &Address,
+    start_ts: u64,
+    max_duration: u64,
+) -> Result<u64, CoreError> {
+    if storage::is_paused(env) {
+        return Err(CoreError::ContractPaused);
+    }
+
+    // 1. Require authorization from the user; it covers the vault deposit and slot reservation
+    user.require_auth();
+
+    let contracts = storage::get_contracts(env).ok_or(CoreError::NotInitialized)?;
+
+    // 2. Verify expert is verified via Identity Registry
+    let is_verified: bool = env.invoke_contract(
+        &contracts.registry,
+        &Symbol::new(env, "is_verified"),
+        vec![env, expert.into_val(env)],
+    );
+    if !is_verified {
+        return Err(CoreError::ExpertNotVerified);
+    }
+
+    // 3. Lock the deposit in the vault
+    let booking_id: u64 = env.invoke_contract(
+        &contracts.vault,
+        &Symbol::new(env, "book_session"),
+        vec![
+            env,
+            user.into_val(env),
+            expert.into_val(env),
+            token.into_val(env),
+            max_duration.into_val(env),
+        ],
+    );
+
+    // 4. Reserve the slot in the calendar against the new booking
+    env.invoke_contract::<()>(
+        &contracts.calendar,
+        &Symbol::new(env, "reserve_slot"),
+        vec![
+            env,
+            user.into_val(env),
+            expert.into_val(env),
+            start_ts.into_val(env),
+            max_duration.into_val(env),
+            booking_id.into_val(env),
+        ],
+    );
+
+    events::slot_booked(env, booking_id, user, expert, start_ts);
+
+    Ok(booking_id)
+}
+
+/// Review a finished booking through the Reputation contract (User-only).
+/// The reputation contract checks the booking against the vault and rejects duplicates.
+pub fn submit_review(
+    env: &Env,
+    user: &Address,
+    booking_id: u64,
+    rating: u32,
+    comment_hash: &BytesN<32>,
+) -> Result<(), CoreError> {
+    if storage::is_paused(env) {
+        return Err(CoreError::ContractPaused);
+    }
+
+    user.require_auth();
+
+    let contracts = storage::get_contracts(env).ok_or(CoreError::NotInitialized)?;
+
+    env.invoke_contract::<()>(
+        &contracts.reputation,
+        &Symbol::new(env, "submit_review"),
+        vec![
+            env,
+            user.into_val(env),
+            booking_id.into_val(env),
+            rating.into_val(env),
+            comment_hash.into_val(env),
+        ],
+    );
+
+    Ok(())
+}
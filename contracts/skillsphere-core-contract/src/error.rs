@@ -0,0 +1,12 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CoreError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    ContractPaused = 3,
+    ExpertNotVerified = 4,
+    NoPendingAdmin = 5,
+}
@@ -0,0 +1,43 @@
+#![allow(deprecated)]
+use crate::types::ContractAddresses;
+use soroban_sdk::{symbol_short, Address, Env};
+
+/// Emitted when the contract is paused or unpaused.
+pub fn contract_paused(env: &Env, paused: bool) {
+    let topics = (symbol_short!("paused"),);
+    env.events().publish(topics, paused);
+}
+
+/// Emitted when admin authority is transferred to a new address.
+pub fn admin_transferred(env: &Env, old_admin: &Address, new_admin: &Address) {
+    let topics = (symbol_short!("adm_xfer"),);
+    env.events()
+        .publish(topics, (old_admin.clone(), new_admin.clone()));
+}
+
+/// Emitted when the admin proposes a successor, who must accept to take over.
+pub fn admin_proposed(env: &Env, admin: &Address, pending_admin: &Address) {
+    let topics = (symbol_short!("adm_prop"),);
+    env.events()
+        .publish(topics, (admin.clone(), pending_admin.clone()));
+}
+
+/// Emitted when the admin withdraws a pending admin proposal.
+pub fn admin_transfer_cancelled(env: &Env, admin: &Address, pending_admin: &Address) {
+    let topics = (symbol_short!("adm_cncl"),);
+    env.events()
+        .publish(topics, (admin.clone(), pending_admin.clone()));
+}
+
+/// Emitted when the coordinated contract addresses are updated.
+pub fn contracts_updated(env: &Env, contracts: &ContractAddresses) {
+    let topics = (symbol_short!("ctr_upd"),);
+    env.events().publish(topics, contracts.clone());
+}
+
+/// Emitted when a calendar slot is reserved and paid for in one atomic call.
+pub fn slot_booked(env: &Env, booking_id: u64, user: &Address, expert: &Address, start_ts: u64) {
+    let topics = (symbol_short!("slot_book"), booking_id);
+    env.events()
+        .publish(topics, (user.clone(), expert.clone(), start_ts));
+}
@@ -0,0 +1,92 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod events;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::error::CoreError;
+use crate::types::ContractAddresses;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+
+#[contract]
+pub struct SkillsphereCoreContract;
+
+#[contractimpl]
+impl SkillsphereCoreContract {
+    /// Initialize the core with an admin and the addresses of the registry, vault,
+    /// calendar and reputation contracts it coordinates.
+    pub fn init(env: Env, admin: Address, contracts: ContractAddresses) -> Result<(), CoreError> {
+        contract::initialize(&env, &admin, &contracts)
+    }
+
+    pub fn pause(env: Env) -> Result<(), CoreError> {
+        contract::pause(&env)
+    }
+
+    pub fn unpause(env: Env) -> Result<(), CoreError> {
+        contract::unpause(&env)
+    }
+
+    /// Propose a new admin (Admin-only)
+    /// The current admin keeps full control until the nominee calls `accept_admin`
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), CoreError> {
+        contract::propose_admin(&env, &new_admin)
+    }
+
+    /// Accept a pending admin proposal (pending admin only)
+    /// The old admin loses its privileges at this point
+    pub fn accept_admin(env: Env) -> Result<(), CoreError> {
+        contract::accept_admin(&env)
+    }
+
+    /// Cancel a pending admin proposal (Admin-only)
+    pub fn cancel_admin_transfer(env: Env) -> Result<(), CoreError> {
+        contract::cancel_admin_transfer(&env)
+    }
+
+    /// Get the proposed admin awaiting acceptance, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        access_control::get_pending_admin(&env)
+    }
+
+    /// Point the core at new protocol contract addresses (Admin-only).
+    pub fn set_contracts(env: Env, contracts: ContractAddresses) -> Result<(), CoreError> {
+        contract::set_contracts(&env, &contracts)
+    }
+
+    /// Get the protocol contract addresses coordinated by the core.
+    pub fn get_contracts(env: Env) -> Option<ContractAddresses> {
+        storage::get_contracts(&env)
+    }
+
+    /// Book a calendar slot and lock its payment in one atomic call (User-only).
+    /// Checks the expert is verified, creates the vault booking paid in `token`, then
+    /// reserves `[start_ts, start_ts + max_duration)` in the calendar. If any step fails
+    /// the whole transaction rolls back. Returns the vault booking ID.
+    pub fn book_slot_and_pay(
+        env: Env,
+        user: Address,
+        expert: Address,
+        token: Address,
+        start_ts: u64,
+        max_duration: u64,
+    ) -> Result<u64, CoreError> {
+        contract::book_slot_and_pay(&env, &user, &expert, &token, start_ts, max_duration)
+    }
+
+    /// Rate a finished booking 1 to 5 in the Reputation contract (User-only).
+    /// Fails and rolls back if the booking is not reviewable or was already reviewed.
+    pub fn submit_review(
+        env: Env,
+        user: Address,
+        booking_id: u64,
+        rating: u32,
+        comment_hash: BytesN<32>,
+    ) -> Result<(), CoreError> {
+        contract::submit_review(&env, &user, booking_id, rating, &comment_hash)
+    }
+}
@@ -0,0 +1,47 @@
+use crate::types::ContractAddresses;
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    Contracts, // ContractAddresses of registry, vault, calendar and reputation
+    IsPaused,
+}
+
+// --- Admin ---
+
+pub fn has_admin(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Admin)
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+// --- Protocol Contracts ---
+
+pub fn set_contracts(env: &Env, contracts: &ContractAddresses) {
+    env.storage().instance().set(&DataKey::Contracts, contracts);
+}
+
+pub fn get_contracts(env: &Env) -> Option<ContractAddresses> {
+    env.storage().instance().get(&DataKey::Contracts)
+}
+
+// --- Pause ---
+
+pub fn is_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::IsPaused)
+        .unwrap_or(false)
+}
+
+pub fn set_paused(env: &Env, paused: bool) {
+    env.storage().instance().set(&DataKey::IsPaused, &paused);
+}
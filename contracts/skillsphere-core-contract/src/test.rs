@@ -0,0 +1,341 @@
+#![cfg(test)]
+
+use super::*;
+use crate::error::CoreError;
+use soroban_sdk::{
+    contracttype, testutils::Address as _, testutils::Ledger, token, vec, Address, BytesN, Env,
+    IntoVal, String, Symbol, Vec,
+};
+
+// Mirror of the calendar's `AvailabilityWindow`, which is not exported by its crate
+#[contracttype]
+#[derive(Clone)]
+struct AvailabilityWindow {
+    day_of_week: u32,
+    start_second: u32,
+    end_second: u32,
+    tz_offset_seconds: i32,
+}
+
+struct Setup {
+    env: Env,
+    client: SkillsphereCoreContractClient<'static>,
+    admin: Address,
+    contracts: ContractAddresses,
+    vault: payment_vault_contract::PaymentVaultContractClient<'static>,
+    calendar: calendar_contract::CalendarContractClient<'static>,
+    reputation: reputation_scoring_contract::ReputationScoringContractClient<'static>,
+    token: token::StellarAssetClient<'static>,
+    oracle: Address,
+    user: Address,
+    expert: Address,
+}
+
+/// Core (not yet initialized) next to the real registry, vault, calendar and reputation
+/// contracts, with a verified expert open all of Thursday 1970-01-01
+fn setup() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expert = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token = token::StellarAssetClient::new(&env, &token_address);
+    token.mint(&user, &100_000);
+
+    let registry_id = env.register(identity_registry_contract::IdentityRegistryContract, ());
+    let registry =
+        identity_registry_contract::IdentityRegistryContractClient::new(&env, &registry_id);
+    registry.init(&admin);
+    registry.add_expert(
+        &admin,
+        &expert,
+        &String::from_str(&env, "ipfs://expert"),
+        &Vec::new(&env),
+    );
+
+    let vault_id = env.register(payment_vault_contract::PaymentVaultContract, ());
+    let vault = payment_vault_contract::PaymentVaultContractClient::new(&env, &vault_id);
+    vault.init(&admin, &token_address, &oracle, &registry_id);
+    vault.set_my_rate(&expert, &token_address, &10_i128);
+
+    let calendar_id = env.register(calendar_contract::CalendarContract, ());
+    let calendar = calendar_contract::CalendarContractClient::new(&env, &calendar_id);
+    calendar.init(&admin, &vault_id, &registry_id);
+    vault.set_calendar_address(&admin, &calendar_id);
+    // The ledger starts on Thursday 1970-01-01; open the whole day
+    let windows = vec![
+        &env,
+        AvailabilityWindow {
+            day_of_week: 4,
+            start_second: 0,
+            end_second: 86_400,
+            tz_offset_seconds: 0,
+        },
+    ];
+    env.invoke_contract::<()>(
+        &calendar_id,
+        &Symbol::new(&env, "set_availability"),
+        vec![&env, expert.into_val(&env), windows.into_val(&env)],
+    );
+
+    let reputation_id = env.register(reputation_scoring_contract::ReputationScoringContract, ());
+    let reputation =
+        reputation_scoring_contract::ReputationScoringContractClient::new(&env, &reputation_id);
+    reputation.init(&admin, &vault_id);
+    vault.set_reputation_address(&admin, &reputation_id);
+
+    let contract_id = env.register(SkillsphereCoreContract, ());
+    let client = SkillsphereCoreContractClient::new(&env, &contract_id);
+    let contracts = ContractAddresses {
+        registry: registry_id,
+        vault: vault_id,
+        calendar: calendar_id,
+        reputation: reputation_id,
+    };
+
+    Setup {
+        env,
+        client,
+        admin,
+        contracts,
+        vault,
+        calendar,
+        reputation,
+        token,
+        oracle,
+        user,
+        expert,
+    }
+}
+
+#[test]
+fn test_initialize() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+    assert_eq!(s.client.get_contracts(), Some(s.contracts.clone()));
+}
+
+#[test]
+fn test_initialize_twice_fails() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+    let res = s.client.try_init(&s.admin, &s.contracts);
+    assert_eq!(res, Err(Ok(CoreError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_book_slot_and_pay_rejects_unverified_expert() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+    let stranger = Address::generate(&s.env);
+
+    let res = s
+        .client
+        .try_book_slot_and_pay(&s.user, &stranger, &s.token.address, &3_600, &1_800);
+    assert_eq!(res, Err(Ok(CoreError::ExpertNotVerified)));
+
+    let token = token::Client::new(&s.env, &s.token.address);
+    assert_eq!(token.balance(&s.user), 100_000);
+}
+
+#[test]
+fn test_book_slot_and_pay_rolls_back_vault_booking_when_slot_fails() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+
+    // Friday is outside the expert's availability
+    let res = s
+        .client
+        .try_book_slot_and_pay(&s.user, &s.expert, &s.token.address, &90_000, &1_800);
+    assert!(res.is_err());
+
+    // The vault booking made before the calendar failure was rolled back
+    assert!(s.vault.get_booking(&1).is_none());
+    let token = token::Client::new(&s.env, &s.token.address);
+    assert_eq!(token.balance(&s.user), 100_000);
+}
+
+#[test]
+fn test_pause_blocks_book_slot_and_pay() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+    s.client.pause();
+
+    let res = s
+        .client
+        .try_book_slot_and_pay(&s.user, &s.expert, &s.token.address, &3_600, &1_800);
+    assert_eq!(res, Err(Ok(CoreError::ContractPaused)));
+}
+
+#[test]
+fn test_pause_blocks_admin_transfer() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+    let new_admin = Address::generate(&s.env);
+    s.client.propose_admin(&new_admin);
+    s.client.pause();
+
+    let paused = Err(Ok(CoreError::ContractPaused));
+    assert_eq!(s.client.try_propose_admin(&new_admin), paused);
+    assert_eq!(s.client.try_accept_admin(), paused);
+    assert_eq!(s.client.try_cancel_admin_transfer(), paused);
+    assert_eq!(s.client.get_pending_admin(), Some(new_admin));
+}
+
+#[test]
+fn test_set_contracts_updates_addresses() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+
+    let mut updated = s.contracts.clone();
+    updated.reputation = Address::generate(&s.env);
+    s.client.set_contracts(&updated);
+    assert_eq!(s.client.get_contracts(), Some(updated));
+}
+
+#[test]
+#[should_panic]
+fn test_set_contracts_requires_admin_auth() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+
+    s.env.mock_auths(&[]);
+    s.client.set_contracts(&s.contracts);
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+    let new_admin = Address::generate(&s.env);
+
+    s.client.propose_admin(&new_admin);
+    assert_eq!(s.client.get_pending_admin(), Some(new_admin.clone()));
+    s.client.accept_admin();
+    assert_eq!(s.client.get_pending_admin(), None);
+    assert_eq!(
+        s.client.try_accept_admin(),
+        Err(Ok(CoreError::NoPendingAdmin))
+    );
+}
+
+#[test]
+fn test_cancel_admin_transfer() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+    let new_admin = Address::generate(&s.env);
+
+    s.client.propose_admin(&new_admin);
+    s.client.cancel_admin_transfer();
+    assert_eq!(s.client.get_pending_admin(), None);
+    assert_eq!(
+        s.client.try_cancel_admin_transfer(),
+        Err(Ok(CoreError::NoPendingAdmin))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_accept_admin_requires_pending_admin_auth() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+    s.client.propose_admin(&Address::generate(&s.env));
+
+    s.env.mock_auths(&[]);
+    s.client.accept_admin();
+}
+
+// ==================== Integration Tests ====================
+
+#[test]
+fn test_protocol_book_slot_and_pay_locks_deposit_and_reserves_slot() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+
+    let booking_id =
+        s.client
+            .book_slot_and_pay(&s.user, &s.expert, &s.token.address, &3_600, &1_800);
+
+    let booking = s.vault.get_booking(&booking_id).unwrap();
+    assert_eq!(booking.user, s.user);
+    assert_eq!(booking.total_deposit, 18_000);
+    let token = token::Client::new(&s.env, &s.token.address);
+    assert_eq!(token.balance(&s.vault.address), 18_000);
+
+    let reservation = s.calendar.get_reservation(&booking_id).unwrap();
+    assert_eq!(reservation.start_ts, 3_600);
+    assert_eq!(reservation.end_ts, 5_400);
+}
+
+#[test]
+fn test_protocol_book_slot_and_pay_rolls_back_deposit_on_overlap() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+    s.client
+        .book_slot_and_pay(&s.user, &s.expert, &s.token.address, &3_600, &1_800);
+
+    let res = s
+        .client
+        .try_book_slot_and_pay(&s.user, &s.expert, &s.token.address, &4_000, &1_800);
+    assert!(res.is_err());
+
+    // Only the first booking's deposit is held
+    let token = token::Client::new(&s.env, &s.token.address);
+    assert_eq!(token.balance(&s.vault.address), 18_000);
+    assert_eq!(token.balance(&s.user), 82_000);
+}
+
+#[test]
+fn test_protocol_review_through_core_after_finalized_session() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+    let booking_id =
+        s.client
+            .book_slot_and_pay(&s.user, &s.expert, &s.token.address, &3_600, &1_800);
+
+    s.vault.mark_session_started(&s.oracle, &booking_id);
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 1_800);
+    s.vault.finalize_session(&s.oracle, &booking_id, &1_800);
+
+    let comment_hash = BytesN::from_array(&s.env, &[7u8; 32]);
+    s.client
+        .submit_review(&s.user, &booking_id, &5, &comment_hash);
+
+    let review = s.reputation.get_review(&booking_id).unwrap();
+    assert_eq!(review.rating, 5);
+    assert_eq!(s.reputation.get_reputation(&s.expert).review_count, 1);
+
+    // A second review of the same booking rolls back
+    let res = s
+        .client
+        .try_submit_review(&s.user, &booking_id, &4, &comment_hash);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_protocol_reclaim_waits_for_reserved_slot() {
+    let s = setup();
+    s.client.init(&s.admin, &s.contracts);
+    // Reserved for late in the day, so the slot ends well after the booking was made
+    let booking_id =
+        s.client
+            .book_slot_and_pay(&s.user, &s.expert, &s.token.address, &80_000, &1_800);
+
+    s.env.ledger().set_timestamp(86_401);
+    let res = s.vault.try_reclaim_stale_session(&s.user, &booking_id);
+    assert!(res.is_err());
+
+    s.env.ledger().set_timestamp(81_800 + 86_401);
+    s.vault.reclaim_stale_session(&s.user, &booking_id);
+    let token = token::Client::new(&s.env, &s.token.address);
+    assert_eq!(token.balance(&s.user), 100_000);
+}
@@ -0,0 +1,11 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Addresses of the protocol contracts coordinated by the core
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractAddresses {
+    pub registry: Address,   // IdentityRegistryContract
+    pub vault: Address,      // PaymentVaultContract
+    pub calendar: Address,   // CalendarContract
+    pub reputation: Address, // ReputationScoringContract
+}
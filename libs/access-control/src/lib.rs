@@ -0,0 +1,160 @@
+#![no_std]
+#![allow(deprecated)]
+
+//! Role-based access control shared by the SkillSphere contracts.
+//!
+//! Role membership lives in the instance storage of the contract that calls
+//! these helpers, so every contract keeps its own, independent role table.
+//! Authorization policy (who may grant what) stays with the contract; this
+//! crate only records membership, checks it and emits the change events.
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Role {
+    /// Manages roles and contract configuration
+    Admin = 0,
+    /// Reviews and moderates user-facing content (e.g. expert verification)
+    Moderator = 1,
+    /// Trusted backend that reports off-chain facts such as session durations
+    Oracle = 2,
+    /// May pause and unpause the contract in an emergency
+    Pauser = 3,
+    /// May replace the contract's WASM
+    Upgrader = 4,
+    /// Receives and withdraws platform fees
+    Treasurer = 5,
+}
+
+/// Roles handed to the initial admin and moved along with admin rights
+pub const ADMIN_ROLES: [Role; 3] = [Role::Admin, Role::Pauser, Role::Upgrader];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessError {
+    /// The caller does not hold the required role
+    MissingRole,
+    /// No admin transfer has been proposed
+    NoPendingAdmin,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum AccessKey {
+    RoleMember(Role, Address),
+    Initialized,  // Set once the admin roles have been handed out
+    PendingAdmin, // Nominee of a two-step admin transfer
+}
+
+/// Check whether `account` holds `role`
+pub fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get(&AccessKey::RoleMember(role, account.clone()))
+        .unwrap_or(false)
+}
+
+/// Require `caller`'s authorization and that it holds `role`
+pub fn require_role(env: &Env, role: Role, caller: &Address) -> Result<(), AccessError> {
+    if !has_role(env, role, caller) {
+        return Err(AccessError::MissingRole);
+    }
+    caller.require_auth();
+    Ok(())
+}
+
+/// Give `role` to `account`, emitting an event on change.
+/// Returns false if the account already held the role.
+pub fn grant_role(env: &Env, role: Role, account: &Address, sender: &Address) -> bool {
+    if has_role(env, role, account) {
+        return false;
+    }
+    env.storage()
+        .instance()
+        .set(&AccessKey::RoleMember(role, account.clone()), &true);
+    env.events().publish(
+        (symbol_short!("role_grnt"), role),
+        (account.clone(), sender.clone()),
+    );
+    true
+}
+
+/// Take `role` away from `account`, emitting an event on change.
+/// Returns false if the account did not hold the role.
+pub fn revoke_role(env: &Env, role: Role, account: &Address, sender: &Address) -> bool {
+    if !has_role(env, role, account) {
+        return false;
+    }
+    env.storage()
+        .instance()
+        .remove(&AccessKey::RoleMember(role, account.clone()));
+    env.events().publish(
+        (symbol_short!("role_rvk"), role),
+        (account.clone(), sender.clone()),
+    );
+    true
+}
+
+/// Grant the initial admin every role in [`ADMIN_ROLES`]
+pub fn init_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&AccessKey::Initialized, &true);
+    for role in ADMIN_ROLES {
+        grant_role(env, role, admin, admin);
+    }
+}
+
+/// Grant [`ADMIN_ROLES`] to `admin` on a contract deployed before role storage existed.
+/// Such contracts only recorded an admin address, so after an in-place upgrade nobody
+/// holds a role. Returns false, changing nothing, once roles have been handed out.
+pub fn migrate_admin(env: &Env, admin: &Address) -> bool {
+    if env.storage().instance().has(&AccessKey::Initialized) {
+        return false;
+    }
+    init_admin(env, admin);
+    true
+}
+
+/// Move every role in [`ADMIN_ROLES`] held by `from` over to `to`
+pub fn transfer_admin_roles(env: &Env, from: &Address, to: &Address) {
+    for role in ADMIN_ROLES {
+        if revoke_role(env, role, from, from) {
+            grant_role(env, role, to, from);
+        }
+    }
+}
+
+/// Nominee of the pending admin transfer, if any
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&AccessKey::PendingAdmin)
+}
+
+/// Nominate `new_admin` to take over from `admin`, who must authorize.
+/// Nothing changes until the nominee accepts; a new proposal replaces any pending one.
+pub fn propose_admin(env: &Env, admin: &Address, new_admin: &Address) {
+    admin.require_auth();
+    env.storage()
+        .instance()
+        .set(&AccessKey::PendingAdmin, new_admin);
+}
+
+/// Complete the transfer proposed by `admin`: the nominee must authorize and receives
+/// `admin`'s [`ADMIN_ROLES`]. Returns the new admin for the contract to record.
+pub fn accept_admin(env: &Env, admin: &Address) -> Result<Address, AccessError> {
+    let pending_admin = get_pending_admin(env).ok_or(AccessError::NoPendingAdmin)?;
+    pending_admin.require_auth();
+    env.storage().instance().remove(&AccessKey::PendingAdmin);
+    transfer_admin_roles(env, admin, &pending_admin);
+    Ok(pending_admin)
+}
+
+/// Withdraw the pending proposal; `admin` must authorize. Returns the withdrawn nominee.
+pub fn cancel_admin_transfer(env: &Env, admin: &Address) -> Result<Address, AccessError> {
+    admin.require_auth();
+    let pending_admin = get_pending_admin(env).ok_or(AccessError::NoPendingAdmin)?;
+    env.storage().instance().remove(&AccessKey::PendingAdmin);
+    Ok(pending_admin)
+}
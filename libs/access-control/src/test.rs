@@ -0,0 +1,158 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{contract, testutils::Address as _, Env};
+
+#[contract]
+struct Host;
+
+#[test]
+fn test_grant_and_revoke_role() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+    let admin = Address::generate(&env);
+    let moderator = Address::generate(&env);
+
+    env.as_contract(&host, || {
+        assert!(!has_role(&env, Role::Moderator, &moderator));
+        assert!(grant_role(&env, Role::Moderator, &moderator, &admin));
+        assert!(has_role(&env, Role::Moderator, &moderator));
+        assert!(!has_role(&env, Role::Oracle, &moderator));
+
+        // Granting twice is a no-op
+        assert!(!grant_role(&env, Role::Moderator, &moderator, &admin));
+
+        assert!(revoke_role(&env, Role::Moderator, &moderator, &admin));
+        assert!(!has_role(&env, Role::Moderator, &moderator));
+        assert!(!revoke_role(&env, Role::Moderator, &moderator, &admin));
+    });
+}
+
+#[test]
+fn test_require_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let host = env.register(Host, ());
+    let pauser = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    env.as_contract(&host, || {
+        grant_role(&env, Role::Pauser, &pauser, &pauser);
+        assert_eq!(require_role(&env, Role::Pauser, &pauser), Ok(()));
+        assert_eq!(
+            require_role(&env, Role::Upgrader, &pauser),
+            Err(AccessError::MissingRole)
+        );
+        assert_eq!(
+            require_role(&env, Role::Pauser, &stranger),
+            Err(AccessError::MissingRole)
+        );
+    });
+}
+
+#[test]
+fn test_transfer_admin_roles() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+    let old_admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    env.as_contract(&host, || {
+        init_admin(&env, &old_admin);
+        grant_role(&env, Role::Moderator, &old_admin, &old_admin);
+
+        transfer_admin_roles(&env, &old_admin, &new_admin);
+
+        for role in ADMIN_ROLES {
+            assert!(!has_role(&env, role, &old_admin));
+            assert!(has_role(&env, role, &new_admin));
+        }
+        // Roles outside the admin set stay with their holder
+        assert!(has_role(&env, Role::Moderator, &old_admin));
+        assert!(!has_role(&env, Role::Moderator, &new_admin));
+    });
+}
+
+#[test]
+fn test_migrate_admin_once() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+    let admin = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    env.as_contract(&host, || {
+        assert!(migrate_admin(&env, &admin));
+        for role in ADMIN_ROLES {
+            assert!(has_role(&env, role, &admin));
+        }
+        assert!(!migrate_admin(&env, &other));
+        assert!(!has_role(&env, Role::Admin, &other));
+    });
+}
+
+#[test]
+fn test_migrate_admin_skips_initialized_contract() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+    let admin = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    env.as_contract(&host, || {
+        init_admin(&env, &admin);
+        // Roles stay set up even after the admin gives up the Admin role
+        revoke_role(&env, Role::Admin, &admin, &admin);
+        assert!(!migrate_admin(&env, &other));
+        assert!(!has_role(&env, Role::Admin, &other));
+    });
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let host = env.register(Host, ());
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    env.as_contract(&host, || {
+        init_admin(&env, &admin);
+        assert_eq!(accept_admin(&env, &admin), Err(AccessError::NoPendingAdmin));
+
+        propose_admin(&env, &admin, &new_admin);
+        assert_eq!(get_pending_admin(&env), Some(new_admin.clone()));
+        // The current admin keeps its roles until the nominee accepts
+        assert!(has_role(&env, Role::Admin, &admin));
+
+        assert_eq!(accept_admin(&env, &admin), Ok(new_admin.clone()));
+        assert_eq!(get_pending_admin(&env), None);
+        for role in ADMIN_ROLES {
+            assert!(!has_role(&env, role, &admin));
+            assert!(has_role(&env, role, &new_admin));
+        }
+    });
+}
+
+#[test]
+fn test_cancel_admin_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let host = env.register(Host, ());
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    // Each step runs in its own frame, as each would be its own contract call
+    env.as_contract(&host, || {
+        init_admin(&env, &admin);
+        assert_eq!(
+            cancel_admin_transfer(&env, &admin),
+            Err(AccessError::NoPendingAdmin)
+        );
+    });
+    env.as_contract(&host, || propose_admin(&env, &admin, &new_admin));
+    env.as_contract(&host, || {
+        assert_eq!(cancel_admin_transfer(&env, &admin), Ok(new_admin));
+        assert_eq!(get_pending_admin(&env), None);
+        assert_eq!(accept_admin(&env, &admin), Err(AccessError::NoPendingAdmin));
+        assert!(has_role(&env, Role::Admin, &admin));
+    });
+}
@@ -0,0 +1,155 @@
+#![no_std]
+#![allow(deprecated)]
+
+//! Timelocked WASM upgrades shared by the SkillSphere contracts.
+//!
+//! An upgrade is announced with `schedule`, can be withdrawn with `cancel`,
+//! and only takes effect through `execute` once its delay has elapsed. The
+//! delay gives users time to exit before contract logic changes. The `*_as_*`
+//! variants also check the caller's role, so a contract entrypoint can hand
+//! each step straight to this crate.
+
+#[cfg(test)]
+mod test;
+
+use access_control::{AccessError, Role};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env};
+
+/// Delay applied until the contract configures its own (2 days)
+pub const DEFAULT_UPGRADE_DELAY: u64 = 2 * 86_400;
+/// Shortest delay a contract may configure (1 day)
+pub const MIN_UPGRADE_DELAY: u64 = 86_400;
+/// Longest delay a contract may configure (30 days)
+pub const MAX_UPGRADE_DELAY: u64 = 30 * 86_400;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUpgrade {
+    pub wasm_hash: BytesN<32>,
+    pub scheduled_at: u64,
+    /// Earliest ledger timestamp at which the upgrade can be executed
+    pub executable_at: u64,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UpgradeError {
+    /// The delay is outside `MIN_UPGRADE_DELAY..=MAX_UPGRADE_DELAY`
+    InvalidDelay,
+    /// No upgrade has been scheduled
+    NoPendingUpgrade,
+    /// The scheduled upgrade's delay has not elapsed yet
+    UpgradeNotReady,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum UpgradeKey {
+    UpgradeDelay,
+    PendingUpgrade,
+}
+
+/// Get the delay between scheduling and executing an upgrade
+pub fn get_delay(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&UpgradeKey::UpgradeDelay)
+        .unwrap_or(DEFAULT_UPGRADE_DELAY)
+}
+
+/// Change the upgrade delay. Already scheduled upgrades keep their original time.
+pub fn set_delay(env: &Env, delay: u64) -> Result<(), UpgradeError> {
+    if !(MIN_UPGRADE_DELAY..=MAX_UPGRADE_DELAY).contains(&delay) {
+        return Err(UpgradeError::InvalidDelay);
+    }
+    env.storage()
+        .instance()
+        .set(&UpgradeKey::UpgradeDelay, &delay);
+    env.events().publish((symbol_short!("upg_delay"),), delay);
+    Ok(())
+}
+
+/// Get the scheduled upgrade, if any
+pub fn get_pending(env: &Env) -> Option<PendingUpgrade> {
+    env.storage().instance().get(&UpgradeKey::PendingUpgrade)
+}
+
+/// Announce an upgrade to `wasm_hash`, replacing any pending one.
+/// Returns the scheduled upgrade.
+pub fn schedule(env: &Env, wasm_hash: BytesN<32>) -> PendingUpgrade {
+    let now = env.ledger().timestamp();
+    let pending = PendingUpgrade {
+        wasm_hash,
+        scheduled_at: now,
+        executable_at: now + get_delay(env),
+    };
+    env.storage()
+        .instance()
+        .set(&UpgradeKey::PendingUpgrade, &pending);
+    env.events().publish(
+        (symbol_short!("upg_sched"),),
+        (pending.wasm_hash.clone(), pending.executable_at),
+    );
+    pending
+}
+
+/// Drop the scheduled upgrade
+pub fn cancel(env: &Env) -> Result<(), UpgradeError> {
+    let pending = get_pending(env).ok_or(UpgradeError::NoPendingUpgrade)?;
+    env.storage().instance().remove(&UpgradeKey::PendingUpgrade);
+    env.events()
+        .publish((symbol_short!("upg_cncl"),), pending.wasm_hash);
+    Ok(())
+}
+
+/// Install the scheduled WASM once its delay has elapsed
+pub fn execute(env: &Env) -> Result<(), UpgradeError> {
+    let pending = get_pending(env).ok_or(UpgradeError::NoPendingUpgrade)?;
+    if env.ledger().timestamp() < pending.executable_at {
+        return Err(UpgradeError::UpgradeNotReady);
+    }
+    env.storage().instance().remove(&UpgradeKey::PendingUpgrade);
+    env.events()
+        .publish((symbol_short!("upg_exec"),), pending.wasm_hash.clone());
+    env.deployer()
+        .update_current_contract_wasm(pending.wasm_hash);
+    Ok(())
+}
+
+/// [`schedule`] on behalf of `caller`, who must hold the Upgrader role
+pub fn schedule_as_upgrader<E: From<AccessError>>(
+    env: &Env,
+    caller: &Address,
+    wasm_hash: BytesN<32>,
+) -> Result<(), E> {
+    access_control::require_role(env, Role::Upgrader, caller)?;
+    schedule(env, wasm_hash);
+    Ok(())
+}
+
+/// [`execute`] on behalf of `caller`, who must hold the Upgrader role
+pub fn execute_as_upgrader<E: From<AccessError> + From<UpgradeError>>(
+    env: &Env,
+    caller: &Address,
+) -> Result<(), E> {
+    access_control::require_role(env, Role::Upgrader, caller)?;
+    Ok(execute(env)?)
+}
+
+/// [`cancel`] on behalf of `caller`, who must hold the Upgrader role
+pub fn cancel_as_upgrader<E: From<AccessError> + From<UpgradeError>>(
+    env: &Env,
+    caller: &Address,
+) -> Result<(), E> {
+    access_control::require_role(env, Role::Upgrader, caller)?;
+    Ok(cancel(env)?)
+}
+
+/// [`set_delay`] on behalf of `caller`, who must hold the Admin role
+pub fn set_delay_as_admin<E: From<AccessError> + From<UpgradeError>>(
+    env: &Env,
+    caller: &Address,
+    delay: u64,
+) -> Result<(), E> {
+    access_control::require_role(env, Role::Admin, caller)?;
+    Ok(set_delay(env, delay)?)
+}
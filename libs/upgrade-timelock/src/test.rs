@@ -0,0 +1,155 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+#[contract]
+struct Host;
+
+#[derive(Debug, PartialEq)]
+enum HostError {
+    Access(AccessError),
+    Upgrade(UpgradeError),
+}
+
+impl From<AccessError> for HostError {
+    fn from(err: AccessError) -> Self {
+        HostError::Access(err)
+    }
+}
+
+impl From<UpgradeError> for HostError {
+    fn from(err: UpgradeError) -> Self {
+        HostError::Upgrade(err)
+    }
+}
+
+fn hash(env: &Env, byte: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[byte; 32])
+}
+
+#[test]
+fn test_schedule_uses_delay() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let host = env.register(Host, ());
+
+    env.as_contract(&host, || {
+        assert_eq!(get_delay(&env), DEFAULT_UPGRADE_DELAY);
+        let pending = schedule(&env, hash(&env, 1));
+        assert_eq!(pending.scheduled_at, 1_000);
+        assert_eq!(pending.executable_at, 1_000 + DEFAULT_UPGRADE_DELAY);
+        assert_eq!(get_pending(&env), Some(pending));
+
+        // Rescheduling replaces the pending upgrade
+        set_delay(&env, MIN_UPGRADE_DELAY).unwrap();
+        let pending = schedule(&env, hash(&env, 2));
+        assert_eq!(pending.executable_at, 1_000 + MIN_UPGRADE_DELAY);
+        assert_eq!(get_pending(&env).unwrap().wasm_hash, hash(&env, 2));
+    });
+}
+
+#[test]
+fn test_set_delay_bounds() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+
+    env.as_contract(&host, || {
+        assert_eq!(
+            set_delay(&env, MIN_UPGRADE_DELAY - 1),
+            Err(UpgradeError::InvalidDelay)
+        );
+        assert_eq!(
+            set_delay(&env, MAX_UPGRADE_DELAY + 1),
+            Err(UpgradeError::InvalidDelay)
+        );
+        assert_eq!(set_delay(&env, MAX_UPGRADE_DELAY), Ok(()));
+        assert_eq!(get_delay(&env), MAX_UPGRADE_DELAY);
+    });
+}
+
+#[test]
+fn test_execute_before_delay_fails() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let host = env.register(Host, ());
+
+    env.as_contract(&host, || {
+        assert_eq!(execute(&env), Err(UpgradeError::NoPendingUpgrade));
+        schedule(&env, hash(&env, 1));
+    });
+
+    env.ledger()
+        .set_timestamp(1_000 + DEFAULT_UPGRADE_DELAY - 1);
+    env.as_contract(&host, || {
+        assert_eq!(execute(&env), Err(UpgradeError::UpgradeNotReady));
+        assert!(get_pending(&env).is_some());
+    });
+}
+
+#[test]
+fn test_cancel() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+
+    env.as_contract(&host, || {
+        assert_eq!(cancel(&env), Err(UpgradeError::NoPendingUpgrade));
+        schedule(&env, hash(&env, 1));
+        assert_eq!(cancel(&env), Ok(()));
+        assert_eq!(get_pending(&env), None);
+        assert_eq!(execute(&env), Err(UpgradeError::NoPendingUpgrade));
+    });
+}
+
+#[test]
+fn test_role_checked_steps() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let host = env.register(Host, ());
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    env.as_contract(&host, || {
+        access_control::init_admin(&env, &admin);
+        assert_eq!(
+            schedule_as_upgrader::<HostError>(&env, &stranger, hash(&env, 1)),
+            Err(HostError::Access(AccessError::MissingRole))
+        );
+        assert_eq!(
+            set_delay_as_admin::<HostError>(&env, &stranger, MIN_UPGRADE_DELAY),
+            Err(HostError::Access(AccessError::MissingRole))
+        );
+        assert_eq!(get_pending(&env), None);
+    });
+    env.as_contract(&host, || {
+        assert_eq!(
+            cancel_as_upgrader::<HostError>(&env, &admin),
+            Err(HostError::Upgrade(UpgradeError::NoPendingUpgrade))
+        );
+    });
+    env.as_contract(&host, || {
+        assert_eq!(
+            set_delay_as_admin::<HostError>(&env, &admin, MIN_UPGRADE_DELAY - 1),
+            Err(HostError::Upgrade(UpgradeError::InvalidDelay))
+        );
+    });
+    env.as_contract(&host, || {
+        assert_eq!(
+            schedule_as_upgrader::<HostError>(&env, &admin, hash(&env, 1)),
+            Ok(())
+        );
+        assert!(get_pending(&env).is_some());
+    });
+    env.as_contract(&host, || {
+        assert_eq!(
+            execute_as_upgrader::<HostError>(&env, &stranger),
+            Err(HostError::Access(AccessError::MissingRole))
+        );
+        assert_eq!(cancel_as_upgrader::<HostError>(&env, &admin), Ok(()));
+        assert_eq!(get_pending(&env), None);
+    });
+}
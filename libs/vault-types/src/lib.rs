@@ -0,0 +1,44 @@
+#![no_std]
+
+//! Booking records of the SkillSphere payment vault.
+//!
+//! The vault stores these types, and the calendar and reputation contracts decode
+//! them from its `get_booking` results. Sharing one definition keeps the
+//! cross-contract encoding in step when a field is added.
+
+use soroban_sdk::{contracttype, Address};
+
+/// Status of a booking in the payment vault
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum BookingStatus {
+    Pending = 0,
+    Complete = 1,
+    Rejected = 2,
+    Reclaimed = 3,
+    DisputedAndResolved = 4,
+    Cancelled = 5,
+    EndedByUser = 6, // User ended a started session early; expert paid for elapsed time
+    EndedByExpert = 7, // Expert ended a started session early; expert paid for elapsed time
+}
+
+/// Record of a consultation booking with deposit locked
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BookingRecord {
+    pub id: u64,                           // Storage key identifier
+    pub user: Address,                     // User who created the booking
+    pub expert: Address,                   // Expert providing consultation
+    pub token: Address,                    // Payment token chosen for this booking
+    pub rate_per_second: i128, // Payment rate per second in atomic units of the payment token
+    pub max_duration: u64,     // Maximum booked duration in seconds
+    pub total_deposit: i128,   // Total deposit (rate_per_second * max_duration)
+    pub status: BookingStatus, // Current booking status
+    pub created_at: u64,       // Ledger timestamp when booking was created
+    pub started_at: Option<u64>, // Ledger timestamp when Oracle marked the session active; None means not yet started
+    pub dispute_user_refund: Option<i128>, // Admin-applied refund in dispute resolution, if resolved by dispute flow
+    pub dispute_expert_pay: Option<i128>, // Admin-applied expert payout in dispute resolution, if resolved by dispute flow
+    pub dispute_remainder_recovered: bool, // True once admin has recovered disputed remainder for this booking
+    pub withdrawn_amount: i128, // Amount already streamed to the expert while the session is live
+}